@@ -0,0 +1,253 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitInt, LitStr};
+
+/// Derives ```freeval::Validate``` from ```#[validate(...)]``` attributes on struct fields.
+///
+/// Supported rules (each accepts an optional ```message = "..."```):
+/// * ```length(min = 3, max = 20)```, ```length(min = 3)```, ```length(max = 20)```, ```length(equal = 5)```
+/// * ```size(min = 18, max = 99)```, ```size(min = 18)```, ```size(max = 99)```, ```size(equal = 5)```
+/// * ```password(min = 8)```
+/// * ```contains(pattern = "...")```
+/// * ```must_match(other = "field")```
+/// * ```required```, ```email```, ```bool```, ```must_be_true```, ```must_be_false```
+///
+/// Rules are declared against the serialized key of each field, so serde's ```rename``` and ```rename_all```
+/// attributes are respected.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(name, "Validate can only be derived for structs with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(name, "Validate can only be derived for structs")),
+    };
+
+    let rename_all = serde_rename(&input.attrs, "rename_all")?;
+    let mut declarations = Vec::new();
+
+    for field in fields {
+        let field_name = match serde_rename(&field.attrs, "rename")? {
+            Some(name) => name.value(),
+            None => {
+                let name = field.ident.as_ref().unwrap().to_string();
+                let name = name.strip_prefix("r#").map(String::from).unwrap_or(name);
+                match &rename_all {
+                    Some(rule) => apply_rename_all(rule, &name)?,
+                    None => name,
+                }
+            }
+        };
+        let mut rules = Vec::new();
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+            attr.parse_nested_meta(|meta| {
+                rules.push(parse_rule(&meta)?);
+                Ok(())
+            })?;
+        }
+
+        if let Some(((rule, message), rest)) = rules.split_first() {
+            let inserts = rest.iter().map(|(rule, message)| quote!(decl.insert(#rule, #message);));
+
+            declarations.push(quote! {
+                let mut decl = ::freeval::RuleDeclaration::new(#field_name, #rule, #message);
+                #(#inserts)*
+                declarations.push(decl);
+            });
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::freeval::Validate for #name #ty_generics #where_clause {
+            fn validate(&self) -> ::std::result::Result<(), ::freeval::ValidationError> {
+                let mut declarations = ::std::vec::Vec::new();
+                #({ #declarations })*
+
+                ::freeval::FreeVal::new(self, declarations).validate()
+            }
+        }
+    })
+}
+
+/// finds the serialized name set by ```#[serde(<key> = "...")]``` or ```#[serde(<key>(serialize = "..."))]```,
+/// skipping other serde arguments
+fn serde_rename(attrs: &[syn::Attribute], key: &str) -> syn::Result<Option<LitStr>> {
+    let mut name = None;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) && meta.input.peek(syn::Token![=]) {
+                name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident(key) && meta.input.peek(syn::token::Paren) {
+                meta.parse_nested_meta(|arg| {
+                    if arg.path.is_ident("serialize") {
+                        name = Some(arg.value()?.parse()?);
+                    } else {
+                        arg.value()?.parse::<LitStr>()?;
+                    }
+                    Ok(())
+                })?;
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::TokenTree>()?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(name)
+}
+
+/// converts a snake_case field ```name``` like serde's ```rename_all``` ```rule```
+fn apply_rename_all(rule: &LitStr, name: &str) -> syn::Result<String> {
+    let pascal: String = name
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+
+    let renamed = match rule.value().as_str() {
+        "lowercase" | "snake_case" => name.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "PascalCase" => pascal,
+        "camelCase" => {
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
+        _ => return Err(syn::Error::new_spanned(rule, "unsupported rename_all rule")),
+    };
+
+    Ok(renamed)
+}
+
+/// arguments of a rule, e.g. ```min = 3, message = "..."```
+#[derive(Default)]
+struct RuleArgs {
+    min: Option<LitInt>,
+    max: Option<LitInt>,
+    equal: Option<LitInt>,
+    pattern: Option<LitStr>,
+    other: Option<LitStr>,
+    message: Option<LitStr>,
+}
+
+impl RuleArgs {
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<RuleArgs> {
+        let mut args = RuleArgs::default();
+
+        // bare rules such as `email` have no arguments
+        if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+            return Ok(args);
+        }
+
+        meta.parse_nested_meta(|arg| {
+            if arg.path.is_ident("min") {
+                args.min = Some(arg.value()?.parse()?);
+            } else if arg.path.is_ident("max") {
+                args.max = Some(arg.value()?.parse()?);
+            } else if arg.path.is_ident("equal") {
+                args.equal = Some(arg.value()?.parse()?);
+            } else if arg.path.is_ident("pattern") {
+                args.pattern = Some(arg.value()?.parse()?);
+            } else if arg.path.is_ident("other") {
+                args.other = Some(arg.value()?.parse()?);
+            } else if arg.path.is_ident("message") {
+                args.message = Some(arg.value()?.parse()?);
+            } else {
+                return Err(arg.error("unsupported validation argument"));
+            }
+
+            Ok(())
+        })?;
+
+        Ok(args)
+    }
+
+    fn message(&self) -> TokenStream2 {
+        match &self.message {
+            Some(message) => quote!(::std::option::Option::Some(#message)),
+            None => quote!(::std::option::Option::None),
+        }
+    }
+}
+
+/// parses a single rule into its ```ValidatorRule``` and error message
+fn parse_rule(meta: &ParseNestedMeta) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let args = RuleArgs::parse(meta)?;
+    let rule = quote!(::freeval::ValidatorRule);
+
+    let tokens = if meta.path.is_ident("length") {
+        match (&args.min, &args.max, &args.equal) {
+            (_, _, Some(equal)) => quote!(#rule::Length(#equal)),
+            (Some(min), Some(max), _) => quote!(#rule::LengthRange((#min, #max))),
+            (Some(min), None, _) => quote!(#rule::MinLength(#min)),
+            (None, Some(max), _) => quote!(#rule::MaxLength(#max)),
+            _ => return Err(meta.error("length requires `min`, `max` or `equal`")),
+        }
+    } else if meta.path.is_ident("size") {
+        match (&args.min, &args.max, &args.equal) {
+            (_, _, Some(equal)) => quote!(#rule::Size(#equal)),
+            (Some(min), Some(max), _) => quote!(#rule::SizeRange((#min, #max))),
+            (Some(min), None, _) => quote!(#rule::MinSize(#min)),
+            (None, Some(max), _) => quote!(#rule::MaxSize(#max)),
+            _ => return Err(meta.error("size requires `min`, `max` or `equal`")),
+        }
+    } else if meta.path.is_ident("password") {
+        match &args.min {
+            Some(min) => quote!(#rule::Password(#min)),
+            None => return Err(meta.error("password requires `min`")),
+        }
+    } else if meta.path.is_ident("contains") {
+        match &args.pattern {
+            Some(pattern) => quote!(#rule::Contains(::std::string::String::from(#pattern))),
+            None => return Err(meta.error("contains requires `pattern`")),
+        }
+    } else if meta.path.is_ident("must_match") {
+        match &args.other {
+            Some(other) => quote!(#rule::MatchField(#other)),
+            None => return Err(meta.error("must_match requires `other`")),
+        }
+    } else if meta.path.is_ident("required") {
+        quote!(#rule::Required)
+    } else if meta.path.is_ident("email") {
+        quote!(#rule::Email)
+    } else if meta.path.is_ident("bool") {
+        quote!(#rule::Bool)
+    } else if meta.path.is_ident("must_be_true") {
+        quote!(#rule::MustBeTrue)
+    } else if meta.path.is_ident("must_be_false") {
+        quote!(#rule::MustBeFalse)
+    } else {
+        return Err(meta.error("unsupported validation rule"));
+    };
+
+    Ok((tokens, args.message()))
+}
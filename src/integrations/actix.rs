@@ -0,0 +1,66 @@
+//! [```actix-web```](https://docs.rs/actix-web) integration, enabled by the ```actix``` feature.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{web, FromRequest, HttpRequest, HttpResponse, ResponseError};
+use serde::de::DeserializeOwned;
+
+use crate::{Validate, ValidationError};
+
+/// Extractor that deserializes a JSON body into ```T``` and validates it.
+/// 
+/// ```T``` declares its rules through [```Validate```], either derived or implemented by running a
+/// [```RuleSet```](crate::RuleSet). A body that can't be deserialized is rejected like actix's ```web::Json```
+/// extractor, while a body that fails validation is rejected with ```422 Unprocessable Entity``` and
+/// [```ValidationError::into_response_body```].
+/// 
+/// ```no_run
+/// use freeval::integrations::actix::Valid;
+/// use freeval::Validate;
+/// 
+/// #[derive(serde::Deserialize, serde::Serialize, Validate)]
+/// struct SignUp {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+/// 
+/// async fn sign_up(Valid(data): Valid<SignUp>) -> String {
+///     format!("welcome {}", data.username)
+/// }
+/// 
+/// let app = actix_web::App::new().route("/sign-up", actix_web::web::post().to(sign_up));
+/// ```
+#[derive(Debug)]
+pub struct Valid<T>(pub T);
+
+impl<T> FromRequest for Valid<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let json = web::Json::<T>::from_request(req, payload);
+
+        Box::pin(async move {
+            let data = json.await?.into_inner();
+            data.validate()?;
+
+            Ok(Valid(data))
+        })
+    }
+}
+
+impl ResponseError for ValidationError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self.clone().into_response_body())
+    }
+}
@@ -0,0 +1,56 @@
+//! [```axum```](https://docs.rs/axum) integration, enabled by the ```axum``` feature.
+
+use ::axum::extract::{FromRequest, Request};
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use ::axum::Json;
+use serde::de::DeserializeOwned;
+
+use crate::{Validate, ValidationError};
+
+/// Extractor that deserializes a JSON body into ```T``` and validates it.
+/// 
+/// ```T``` declares its rules through [```Validate```], either derived or implemented by running a
+/// [```RuleSet```](crate::RuleSet). A body that can't be deserialized is rejected like axum's ```Json``` extractor,
+/// while a body that fails validation is rejected with ```422 Unprocessable Entity``` and
+/// [```ValidationError::into_response_body```].
+/// 
+/// ```no_run
+/// use freeval::integrations::axum::Valid;
+/// use freeval::Validate;
+/// 
+/// #[derive(serde::Deserialize, serde::Serialize, Validate)]
+/// struct SignUp {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+/// 
+/// async fn sign_up(Valid(data): Valid<SignUp>) -> String {
+///     format!("welcome {}", data.username)
+/// }
+/// 
+/// let app: axum::Router = axum::Router::new().route("/sign-up", axum::routing::post(sign_up));
+/// ```
+#[derive(Debug)]
+pub struct Valid<T>(pub T);
+
+impl<T, S> FromRequest<S> for Valid<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(data) = Json::<T>::from_request(req, state).await.map_err(IntoResponse::into_response)?;
+        data.validate().map_err(IntoResponse::into_response)?;
+
+        Ok(Valid(data))
+    }
+}
+
+impl IntoResponse for ValidationError {
+    fn into_response(self) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, Json(self.into_response_body())).into_response()
+    }
+}
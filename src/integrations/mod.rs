@@ -0,0 +1,7 @@
+//! Extractors for web frameworks. Each integration is opt-in through the Cargo feature of the same name.
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "actix")]
+pub mod actix;
@@ -1,14 +1,29 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
 use serde::Serialize;
+use serde_json::{Map, Value};
 
 mod validators;
+mod locale;
 pub mod macros;
+pub mod integrations;
 
 use validators::*;
+pub use validators::{password_strength, InnerValidationResult, PasswordPolicy, Validator};
+pub use freeval_derive::Validate;
+pub use locale::Locale;
+pub use std::ops::Bound;
 
 type ValidatorErrorType = Option<String>;
 
 /// Validation rules used by ```FreeVal``` to validate your input struct.  
+#[derive(Debug, Clone)]
 pub enum ValidatorRule {
     /// validates length of string
     Length(usize),
@@ -16,30 +31,617 @@ pub enum ValidatorRule {
     MaxLength(usize),
     /// validates minimum length of string
     MinLength(usize),
-    /// validates size of number
+    /// validates size of number (or numeric string such as ```"42"```)
     Size(isize),
-    /// validates maximum size of number
+    /// validates maximum size of number (or numeric string)
     MaxSize(isize),
-    /// validates minimum size of number 
+    /// validates minimum size of number (or numeric string)
     MinSize(isize),
-    /// validates boolean value
+    /// validates minimum size of number (or numeric string) with a 64-bit bound
+    MinSizeI64(i64),
+    /// validates maximum size of number (or numeric string) with a 64-bit bound
+    MaxSizeU64(u64),
+    /// validates that number is strictly greater than a number
+    GreaterThan(isize),
+    /// validates that number is strictly less than a number
+    LessThan(isize),
+    /// validates that number is even
+    Even,
+    /// validates that number is odd
+    Odd,
+    /// validates that number is a multiple of another number
+    MultipleOf(isize),
+    /// validates that integer lands on a grid of ```step``` increments from ```base```, i.e.
+    /// ```(value - base) % step == 0```. A zero ```step``` always fails.
+    StepFrom { base: isize, step: isize },
+    /// validates that value is a boolean (```true``` or ```false```)
     Bool,
+    /// validates that boolean value is true
+    MustBeTrue,
+    /// validates that boolean value is false
+    MustBeFalse,
     /// validates password with minimum length
     Password(usize),
-    /// validates value is not null
+    /// validates password against a configurable [```PasswordPolicy```]
+    PasswordPolicy(PasswordPolicy),
+    /// validates that the [```password_strength```] score of password is at least a minimum (0 to 100)
+    PasswordStrength(u8),
+    /// validates value is not null. When it fails (as an error), the remaining rules of the field are skipped.
     Required,
     /// validates email address
+    #[cfg(feature = "regex")]
     Email,
-    /// validates range of string length
+    /// validates that string value matches at least one of the regular expressions, e.g. several ID formats.
+    /// Patterns are compiled once and cached. An invalid pattern never matches.
+    #[cfg(feature = "regex")]
+    MatchesAny(&'static [&'static str]),
+    /// validates range of string length (inclusive of both bounds)
     LengthRange((isize, isize)),
-    /// validates range of int size
+    /// validates range of int size, also of numeric strings (inclusive of both bounds)
     SizeRange((isize, isize)),
-    /// validates that string value contains another string
-    Contains(&'static str)
+    /// validates string length with inclusive, exclusive or unbounded ends
+    LengthBounds((Bound<usize>, Bound<usize>)),
+    /// validates int size (or numeric string) with inclusive, exclusive or unbounded ends
+    SizeBounds((Bound<isize>, Bound<isize>)),
+    /// validates finite number (or numeric string) with inclusive, exclusive or unbounded ends
+    FloatBounds((Bound<f64>, Bound<f64>)),
+    /// validates that number (or numeric string) is finite and greater than zero
+    FloatPositive,
+    /// validates that number (or numeric string) is finite and less than zero
+    FloatNegative,
+    /// validates that number (or numeric string) is finite and not zero
+    FloatNonZero,
+    /// validates that string value contains another string, which may be computed at runtime
+    Contains(String),
+    /// validates that string value contains another string, ignoring case
+    ContainsIgnoreCase(String),
+    /// validates that string value contains all of the given strings
+    ContainsAll(&'static [&'static str]),
+    /// validates that string value contains at least one of the given strings
+    ContainsAny(&'static [&'static str]),
+    /// validates that string value doesn't contain whitespace
+    NoWhitespace,
+    /// validates that string value has no uppercase letters
+    Lowercase,
+    /// validates that string value has no lowercase letters
+    Uppercase,
+    /// validates that string value has no leading or trailing whitespace
+    Trimmed,
+    /// validates that string value has non-whitespace content
+    NonEmpty,
+    /// validates that the number of words in string value is within range (inclusive of both bounds)
+    WordCountRange((usize, usize)),
+    /// validates that string value has at least a number of words
+    MinWords(usize),
+    /// validates that string value has at most a number of words
+    MaxWords(usize),
+    /// validates that string value doesn't contain line breaks
+    SingleLine,
+    /// validates RFC 3339 date-time string
+    #[cfg(feature = "regex")]
+    DateTime,
+    /// validates ```YYYY-MM-DD``` date string
+    #[cfg(feature = "regex")]
+    Date,
+    /// validates 24-hour ```HH:MM``` or ```HH:MM:SS``` time string
+    Time,
+    /// validates IANA time zone name
+    Timezone,
+    /// validates 5 or 6 field cron expression
+    Cron,
+    /// validates uppercase ISO 4217 currency code
+    CurrencyCode,
+    /// validates uppercase ISO 3166-1 alpha-2 country code
+    CountryCode,
+    /// validates postal code in the format of a country (ISO 3166-1 alpha-2 code, e.g. ```US```). Countries
+    /// without a known format accept 2 to 10 letters and digits, optionally separated by a space or hyphen.
+    PostalCode(&'static str),
+    /// validates EU VAT identification number: country prefix (e.g. ```DE```) and that country's format
+    VatNumber,
+    /// validates IBAN (length per country and mod-97 checksum)
+    Iban,
+    /// validates port number (1 to 65535)
+    Port,
+    /// validates RFC 1123 hostname
+    Hostname,
+    /// validates EAN-13 barcode (13 digits and check digit), given as a string or integer
+    Ean13,
+    /// validates UPC-A barcode (12 digits and check digit), given as a string or integer
+    UpcA,
+    /// validates ISBN-10 (mod-11 check digit, ```X``` allowed). Hyphens and spaces are ignored.
+    Isbn10,
+    /// validates ISBN-13 (mod-10 check digit). Hyphens and spaces are ignored.
+    Isbn13,
+    /// validates ULID (26 uppercase Crockford base32 chars)
+    Ulid,
+    /// validates hyphenated UUID of the given version (e.g. ```UuidVersion(4)```)
+    UuidVersion(u8),
+    /// validates that string value contains only ASCII chars
+    Ascii,
+    /// validates that string value contains only printable ASCII chars (no control chars)
+    AsciiPrintable,
+    /// validates Luhn check digit of a digit string or integer
+    Luhn,
+    /// validates that number (or numeric string) has at most the given decimal places
+    MaxDecimalPlaces(usize),
+    /// validates that string value is a JSON array
+    JsonArray,
+    /// validates that string value is a JSON object
+    JsonObject,
+    /// validates that the number of array items is within a range (inclusive of both bounds)
+    ArrayLength((usize, usize)),
+    /// validates that array items are distinct
+    UniqueItems,
+    /// validates that array contains an item equal to a literal. Non-string items are compared by their JSON
+    /// representation, like ```Equals```.
+    ArrayContains(&'static str),
+    /// validates fully-qualified domain name (at least two labels and a TLD of letters)
+    Domain,
+    /// validates absolute URL with an ```http``` or ```https``` scheme
+    HttpUrl,
+    /// validates css color (hex, ```rgb()```, ```rgba()```, ```hsl()``` or ```hsla()```)
+    CssColor,
+    /// validates that file name has one of the given extensions (case-insensitive)
+    FileExtension(&'static [&'static str]),
+    /// validates MIME type (```type/subtype``` with optional parameters)
+    MimeType,
+    /// validates that value equals a literal. Non-string values are compared by their JSON representation.
+    Equals(&'static str),
+    /// validates that value doesn't equal a literal. Non-string values are compared by their JSON representation.
+    NotEquals(&'static str),
+    /// validates that value equals a literal, ignoring case.
+    EqualsIgnoreCase(&'static str),
+    /// validates that string value is one of the variant names of an enum, as serialized by serde. Names are
+    /// compared exactly, so with ```#[serde(rename_all = "lowercase")]``` list the lowercase names.
+    Enum(&'static [&'static str]),
+    /// validates value against other fields using a user defined ```check```.
+    /// 
+    /// ```check``` receives the field's value and the values of ```fields``` (in order). Missing fields are passed as ```null```.
+    DependsOn {
+        fields: &'static [&'static str],
+        check: fn(&Value, &[&Value]) -> bool,
+    },
+    /// validates size (in bytes) against the limit declared for the MIME type in ```mime_field```
+    SizeLimitByMime {
+        mime_field: &'static str,
+        limits: &'static [(&'static str, u64)],
+    },
+    /// validates that value matches the value of another field
+    MatchField(&'static str),
+    /// validates that value differs from the value of another field
+    DifferentField(&'static str),
+    /// validates that value is not null when another field is not null, e.g. ```cvv``` when ```card_number``` is set
+    RequiredWith(&'static str),
+    /// validates that value is not null when another field is missing or null, e.g. ```email``` when ```phone``` is not set
+    RequiredWithout(&'static str),
+    /// validates that the Levenshtein distance between value and another ```field``` is within ```min``` and ```max``` (inclusive)
+    EditDistance {
+        field: &'static str,
+        max: Option<usize>,
+        min: Option<usize>,
+    },
+    /// validates value with a user defined [```Validator```]
+    /// 
+    /// The validator is shared so rules can be cloned. A ```Box<dyn Validator>``` converts with ```Arc::from```.
+    Boxed(Arc<dyn Validator>),
+    /// validates value with a user defined async check, e.g. a database lookup. Create it with
+    /// [```ValidatorRule::from_async```]. Only [```FreeVal::validate_async```] runs it, also when nested in other rules
+    /// such as ```Optional```; other methods fail it since they can't tell whether the value is valid.
+    #[cfg(feature = "async")]
+    Async(AsyncCheck),
+    /// validates each element of an array with the inner rule. Errors are reported under indexed keys (e.g. ```tags[2]```).
+    Each(Box<ValidatorRule>),
+    /// validates value with the inner rule only when it's not null
+    Optional(Box<ValidatorRule>),
+    /// validates value with the ```then``` rule only when another ```field``` equals a literal (compared like
+    /// ```Equals```). Passes when the condition is not met.
+    When {
+        field: &'static str,
+        equals: &'static str,
+        then: Box<ValidatorRule>,
+    },
+    /// validates that value satisfies at least one of the inner rules
+    AnyOf(Vec<ValidatorRule>),
+    /// validates that value does not satisfy the inner rule
+    Not(Box<ValidatorRule>),
+}
+
+/// Check of a ```ValidatorRule::Async``` rule: receives the field's value and resolves to whether it's valid.
+/// Created by [```ValidatorRule::from_async```].
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub struct AsyncCheck(Arc<dyn Fn(Value) -> AsyncCheckFuture + Send + Sync>);
+
+/// future returned by an [```AsyncCheck```]
+#[cfg(feature = "async")]
+type AsyncCheckFuture = Pin<Box<dyn Future<Output = bool> + Send>>;
+
+#[cfg(feature = "async")]
+impl fmt::Debug for AsyncCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AsyncCheck")
+    }
+}
+
+impl ValidatorRule {
+    /// Creates a ```ValidatorRule::Async``` rule from an async ```check```, e.g.
+    /// ```ValidatorRule::from_async(|value| async move { value != "admin" })```.
+    #[cfg(feature = "async")]
+    pub fn from_async<F, Fut>(check: F) -> ValidatorRule
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        ValidatorRule::Async(AsyncCheck(Arc::new(move |value| Box::pin(check(value)))))
+    }
+
+    /// Returns the (English) message this rule reports for ```field``` when it fails, e.g. to show hints in a
+    /// front-end before submitting.
+    /// 
+    /// Messages are built from the rule's parameters without running any validator, and match validation reports
+    /// except where those depend on the value or on other fields: ```ContainsAll``` lists every substring rather
+    /// than the missing ones, ```EditDistance``` omits the distance and ```SizeLimitByMime``` doesn't name the MIME
+    /// type. ```Boxed``` rules report a generic message.
+    pub fn default_message(&self, field: &str) -> String {
+        match self {
+            ValidatorRule::Length(v) => length_message(field, v, &LengthType::Exact),
+            ValidatorRule::MaxLength(v) => length_message(field, v, &LengthType::Max),
+            ValidatorRule::MinLength(v) => length_message(field, v, &LengthType::Min),
+            ValidatorRule::Size(v) => size_message(field, v, &LengthType::Exact),
+            ValidatorRule::MaxSize(v) => size_message(field, v, &LengthType::Max),
+            ValidatorRule::MinSize(v) => size_message(field, v, &LengthType::Min),
+            ValidatorRule::MinSizeI64(v) => size_message(field, v, &LengthType::Min),
+            ValidatorRule::MaxSizeU64(v) => size_message(field, v, &LengthType::Max),
+            ValidatorRule::GreaterThan(v) => format!("'{}' field must be greater than {}.", field, v),
+            ValidatorRule::LessThan(v) => format!("'{}' field must be less than {}.", field, v),
+            ValidatorRule::Even => format!("'{}' field must be an even number.", field),
+            ValidatorRule::Odd => format!("'{}' field must be an odd number.", field),
+            ValidatorRule::MultipleOf(0) => format!("'{}' field cannot be validated as a multiple of 0.", field),
+            ValidatorRule::MultipleOf(v) => format!("'{}' field must be a multiple of {}.", field, v),
+            ValidatorRule::StepFrom { step: 0, .. } => format!("'{}' field cannot be validated with a step of 0.", field),
+            ValidatorRule::StepFrom { base, step } => {
+                format!("'{}' field must be {} plus a multiple of {}.", field, base, step)
+            }
+            ValidatorRule::Bool => format!("'{}' field must be a boolean.", field),
+            ValidatorRule::MustBeTrue => format!("'{}' field must be true.", field),
+            ValidatorRule::MustBeFalse => format!("'{}' field must be false.", field),
+            ValidatorRule::Password(min_len) => password_policy_message(field, &PasswordPolicy::new(*min_len)),
+            ValidatorRule::PasswordPolicy(policy) => password_policy_message(field, policy),
+            ValidatorRule::PasswordStrength(_) => {
+                format!("'{}' field is too weak. Use a longer password mixing letters, digits and symbols.", field)
+            }
+            ValidatorRule::Required => format!("'{}' field cannot be null.", field),
+            #[cfg(feature = "regex")]
+            ValidatorRule::Email => format!("'{}' field must be a valid email address", field),
+            #[cfg(feature = "regex")]
+            ValidatorRule::MatchesAny(patterns) => {
+                format!("'{}' field must match one of the patterns: {}.", field, patterns.join(", "))
+            }
+            ValidatorRule::LengthRange((min, max)) => range_message(field, min, max, &RangeType::Length),
+            ValidatorRule::SizeRange((min, max)) => range_message(field, min, max, &RangeType::Size),
+            ValidatorRule::LengthBounds((min, max)) => {
+                format!("'{}' field's length must be {}.", field, describe_bounds(min, max))
+            }
+            ValidatorRule::SizeBounds((min, max)) => format!("'{}' field must be {}.", field, describe_bounds(min, max)),
+            ValidatorRule::FloatBounds((min, max)) => format!("'{}' field must be {}.", field, describe_bounds(min, max)),
+            ValidatorRule::FloatPositive => format!("'{}' field must be a positive number.", field),
+            ValidatorRule::FloatNegative => format!("'{}' field must be a negative number.", field),
+            ValidatorRule::FloatNonZero => format!("'{}' field must be a non-zero number.", field),
+            ValidatorRule::Contains(v) => format!("'{}' field must contain  '{}'. Please check again.", field, v),
+            ValidatorRule::ContainsIgnoreCase(v) => format!("'{}' field must contain '{}' (case-insensitive).", field, v),
+            ValidatorRule::ContainsAll(v) => format!("'{}' field must contain '{}'.", field, v.join("', '")),
+            ValidatorRule::ContainsAny(v) => format!("'{}' field must contain one of '{}'.", field, v.join("', '")),
+            ValidatorRule::NoWhitespace => format!("'{}' field must not contain whitespace.", field),
+            ValidatorRule::Lowercase => format!("'{}' field must be lowercase.", field),
+            ValidatorRule::Uppercase => format!("'{}' field must be uppercase.", field),
+            ValidatorRule::Trimmed => format!("'{}' field must not start or end with whitespace.", field),
+            ValidatorRule::NonEmpty => format!("'{}' field must not be empty.", field),
+            ValidatorRule::WordCountRange((min, max)) => {
+                format!("'{}' field must have between {} and {} words.", field, min, max)
+            }
+            ValidatorRule::MinWords(v) => format!("'{}' field must have at least {} words.", field, v),
+            ValidatorRule::MaxWords(v) => format!("'{}' field must have at most {} words.", field, v),
+            ValidatorRule::SingleLine => format!("'{}' field must be a single line.", field),
+            #[cfg(feature = "regex")]
+            ValidatorRule::DateTime => format!("'{}' field must be a valid RFC 3339 date-time.", field),
+            #[cfg(feature = "regex")]
+            ValidatorRule::Date => format!("'{}' field must be a valid date in YYYY-MM-DD format.", field),
+            ValidatorRule::Time => format!("'{}' field must be a valid time in HH:MM or HH:MM:SS format.", field),
+            ValidatorRule::Timezone => format!("'{}' field must be a valid IANA time zone.", field),
+            ValidatorRule::Cron => format!("'{}' field must be a valid cron expression.", field),
+            ValidatorRule::CurrencyCode => format!("'{}' field must be a valid ISO 4217 currency code.", field),
+            ValidatorRule::CountryCode => format!("'{}' field must be a valid ISO 3166-1 alpha-2 country code.", field),
+            ValidatorRule::PostalCode(country) => format!("'{}' field must be a valid {} postal code.", field, country),
+            ValidatorRule::VatNumber => format!("'{}' field must be a valid VAT number.", field),
+            ValidatorRule::Iban => format!("'{}' field must be a valid IBAN.", field),
+            ValidatorRule::Port => format!("'{}' field must be a port number between 1 and 65535.", field),
+            ValidatorRule::Hostname => format!("'{}' field must be a valid hostname.", field),
+            ValidatorRule::Ean13 => format!("'{}' field must be a valid EAN-13 barcode.", field),
+            ValidatorRule::UpcA => format!("'{}' field must be a valid UPC-A barcode.", field),
+            ValidatorRule::Isbn10 => format!("'{}' field must be a valid ISBN-10.", field),
+            ValidatorRule::Isbn13 => format!("'{}' field must be a valid ISBN-13.", field),
+            ValidatorRule::Ulid => format!("'{}' field must be a valid ULID.", field),
+            ValidatorRule::UuidVersion(v) => format!("'{}' field must be a valid version {} UUID.", field, v),
+            ValidatorRule::Ascii => format!("'{}' field must contain only ASCII characters.", field),
+            ValidatorRule::AsciiPrintable => format!("'{}' field must contain only printable ASCII characters.", field),
+            ValidatorRule::Luhn => format!("'{}' field must be a number with a valid Luhn check digit.", field),
+            ValidatorRule::MaxDecimalPlaces(v) => {
+                format!("'{}' field must be a number with at most {} decimal places.", field, v)
+            }
+            ValidatorRule::JsonArray => format!("'{}' field must be a JSON array.", field),
+            ValidatorRule::JsonObject => format!("'{}' field must be a JSON object.", field),
+            ValidatorRule::ArrayLength((min, max)) => {
+                format!("'{}' field must have between {} and {} items.", field, min, max)
+            }
+            ValidatorRule::UniqueItems => format!("'{}' field must not contain duplicate items.", field),
+            ValidatorRule::ArrayContains(v) => format!("'{}' field must contain '{}'.", field, v),
+            ValidatorRule::Domain => format!("'{}' field must be a valid domain name.", field),
+            ValidatorRule::HttpUrl => format!("'{}' field must be a valid http or https URL.", field),
+            ValidatorRule::CssColor => {
+                format!("'{}' field must be a valid css color (hex, rgb(), rgba(), hsl() or hsla()).", field)
+            }
+            ValidatorRule::FileExtension(v) => {
+                format!("'{}' field must have one of the extensions: {}.", field, v.join(", "))
+            }
+            ValidatorRule::MimeType => format!("'{}' field must be a valid MIME type.", field),
+            ValidatorRule::Equals(v) => format!("'{}' field must be equal to '{}'.", field, v),
+            ValidatorRule::NotEquals(v) => format!("'{}' field must not be equal to '{}'.", field, v),
+            ValidatorRule::EqualsIgnoreCase(v) => format!("'{}' field must be equal to '{}' (case-insensitive).", field, v),
+            ValidatorRule::Enum(v) => format!("'{}' field must be one of: {}.", field, v.join(", ")),
+            ValidatorRule::DependsOn { fields, .. } => {
+                format!("'{}' field is inconsistent with '{}'.", field, fields.join("', '"))
+            }
+            ValidatorRule::SizeLimitByMime { mime_field, .. } => {
+                format!("'{}' field must not exceed the size limit of the MIME type in '{}'.", field, mime_field)
+            }
+            ValidatorRule::MatchField(other) => format!("'{}' field must match '{}'.", field, other),
+            ValidatorRule::DifferentField(other) => format!("'{}' field must be different from '{}'.", field, other),
+            ValidatorRule::RequiredWith(other) => format!("'{}' field is required when '{}' is present.", field, other),
+            ValidatorRule::RequiredWithout(other) => {
+                format!("'{}' field is required when '{}' is not present.", field, other)
+            }
+            ValidatorRule::EditDistance { field: other, max, min } => {
+                format!("'{}' field's distance from '{}' must be {}.", field, other, describe_distance(*min, *max))
+            }
+            ValidatorRule::Boxed(_) => format!("'{}' field is invalid.", field),
+            #[cfg(feature = "async")]
+            ValidatorRule::Async(_) => async_message(field),
+            ValidatorRule::Each(inner) | ValidatorRule::Optional(inner) | ValidatorRule::When { then: inner, .. } => {
+                inner.default_message(field)
+            }
+            ValidatorRule::AnyOf(rules) => {
+                rules.iter().map(|rule| rule.default_message(field)).collect::<Vec<_>>().join(" or ")
+            }
+            ValidatorRule::Not(inner) => format!("'{}' field must not satisfy: {}", field, inner.default_message(field)),
+        }
+    }
+
+    /// Returns the stable, machine-readable code reported when this rule fails.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidatorRule::Length(_) => "length",
+            ValidatorRule::MaxLength(_) => "too_long",
+            ValidatorRule::MinLength(_) => "too_short",
+            ValidatorRule::Size(_) => "size",
+            ValidatorRule::MaxSize(_) => "too_large",
+            ValidatorRule::MinSize(_) => "too_small",
+            ValidatorRule::MinSizeI64(_) => "too_small",
+            ValidatorRule::MaxSizeU64(_) => "too_large",
+            ValidatorRule::GreaterThan(_) => "not_greater_than",
+            ValidatorRule::LessThan(_) => "not_less_than",
+            ValidatorRule::Even => "not_even",
+            ValidatorRule::Odd => "not_odd",
+            ValidatorRule::MultipleOf(_) => "not_multiple_of",
+            ValidatorRule::StepFrom { .. } => "off_step",
+            ValidatorRule::Bool => "not_bool",
+            ValidatorRule::MustBeTrue => "not_true",
+            ValidatorRule::MustBeFalse => "not_false",
+            ValidatorRule::Password(_) => "weak_password",
+            ValidatorRule::PasswordPolicy(_) | ValidatorRule::PasswordStrength(_) => "weak_password",
+            ValidatorRule::Required => "required",
+            #[cfg(feature = "regex")]
+            ValidatorRule::Email => "invalid_email",
+            #[cfg(feature = "regex")]
+            ValidatorRule::MatchesAny(_) => "no_pattern_matched",
+            ValidatorRule::LengthRange(_) => "length_out_of_range",
+            ValidatorRule::SizeRange(_) => "size_out_of_range",
+            ValidatorRule::LengthBounds(_) => "length_out_of_range",
+            ValidatorRule::SizeBounds(_) | ValidatorRule::FloatBounds(_) => "size_out_of_range",
+            ValidatorRule::FloatPositive => "not_positive",
+            ValidatorRule::FloatNegative => "not_negative",
+            ValidatorRule::FloatNonZero => "zero",
+            ValidatorRule::Contains(_) | ValidatorRule::ContainsIgnoreCase(_) => "missing_substring",
+            ValidatorRule::ContainsAll(_) | ValidatorRule::ContainsAny(_) => "missing_substring",
+            ValidatorRule::NoWhitespace => "contains_whitespace",
+            ValidatorRule::Lowercase => "not_lowercase",
+            ValidatorRule::Uppercase => "not_uppercase",
+            ValidatorRule::Trimmed => "not_trimmed",
+            ValidatorRule::NonEmpty => "empty",
+            ValidatorRule::WordCountRange(_) => "word_count_out_of_range",
+            ValidatorRule::MinWords(_) => "too_few_words",
+            ValidatorRule::MaxWords(_) => "too_many_words",
+            ValidatorRule::SingleLine => "multiline",
+            #[cfg(feature = "regex")]
+            ValidatorRule::DateTime => "invalid_datetime",
+            #[cfg(feature = "regex")]
+            ValidatorRule::Date => "invalid_date",
+            ValidatorRule::Time => "invalid_time",
+            ValidatorRule::Timezone => "invalid_timezone",
+            ValidatorRule::Cron => "invalid_cron",
+            ValidatorRule::CurrencyCode => "invalid_currency_code",
+            ValidatorRule::CountryCode => "invalid_country_code",
+            ValidatorRule::PostalCode(_) => "invalid_postal_code",
+            ValidatorRule::VatNumber => "invalid_vat_number",
+            ValidatorRule::Iban => "invalid_iban",
+            ValidatorRule::Port => "invalid_port",
+            ValidatorRule::Hostname => "invalid_hostname",
+            ValidatorRule::Ean13 => "invalid_ean13",
+            ValidatorRule::UpcA => "invalid_upc_a",
+            ValidatorRule::Isbn10 => "invalid_isbn10",
+            ValidatorRule::Isbn13 => "invalid_isbn13",
+            ValidatorRule::Ulid => "invalid_ulid",
+            ValidatorRule::UuidVersion(_) => "invalid_uuid",
+            ValidatorRule::Ascii => "not_ascii",
+            ValidatorRule::AsciiPrintable => "not_ascii_printable",
+            ValidatorRule::Luhn => "invalid_checksum",
+            ValidatorRule::MaxDecimalPlaces(_) => "too_many_decimal_places",
+            ValidatorRule::JsonArray => "not_json_array",
+            ValidatorRule::JsonObject => "not_json_object",
+            ValidatorRule::ArrayLength(_) => "array_length_out_of_range",
+            ValidatorRule::UniqueItems => "duplicate_items",
+            ValidatorRule::ArrayContains(_) => "missing_item",
+            ValidatorRule::Domain => "invalid_domain",
+            ValidatorRule::HttpUrl => "invalid_url",
+            ValidatorRule::CssColor => "invalid_color",
+            ValidatorRule::FileExtension(_) => "invalid_extension",
+            ValidatorRule::MimeType => "invalid_mime_type",
+            ValidatorRule::Equals(_) => "not_equal",
+            ValidatorRule::NotEquals(_) => "equal",
+            ValidatorRule::EqualsIgnoreCase(_) => "not_equal",
+            ValidatorRule::Enum(_) => "unknown_variant",
+            ValidatorRule::DependsOn { .. } => "inconsistent",
+            ValidatorRule::SizeLimitByMime { .. } => "too_large",
+            ValidatorRule::MatchField(_) => "mismatch",
+            ValidatorRule::DifferentField(_) => "not_different",
+            ValidatorRule::RequiredWith(_) | ValidatorRule::RequiredWithout(_) => "required",
+            ValidatorRule::EditDistance { .. } => "edit_distance_out_of_range",
+            ValidatorRule::Boxed(validator) => validator.code(),
+            #[cfg(feature = "async")]
+            ValidatorRule::Async(_) => "custom",
+            ValidatorRule::Each(inner) => inner.code(),
+            ValidatorRule::Optional(inner) => inner.code(),
+            ValidatorRule::When { then, .. } => then.code(),
+            ValidatorRule::AnyOf(_) => "no_rule_satisfied",
+            ValidatorRule::Not(_) => "negated_rule_satisfied",
+        }
+    }
+
+    /// whether this rule is or contains a ```ValidatorRule::Async``` rule
+    #[cfg(feature = "async")]
+    fn is_async(&self) -> bool {
+        match self {
+            ValidatorRule::Async(_) => true,
+            ValidatorRule::Each(inner)
+            | ValidatorRule::Optional(inner)
+            | ValidatorRule::When { then: inner, .. }
+            | ValidatorRule::Not(inner) => inner.is_async(),
+            ValidatorRule::AnyOf(rules) => rules.iter().any(|rule| rule.is_async()),
+            _ => false,
+        }
+    }
+
+    /// whether the value checked by this rule is a secret that must not appear in messages
+    fn is_sensitive(&self) -> bool {
+        match self {
+            ValidatorRule::Password(_) | ValidatorRule::PasswordPolicy(_) | ValidatorRule::PasswordStrength(_) => true,
+            ValidatorRule::Each(inner)
+            | ValidatorRule::Optional(inner)
+            | ValidatorRule::When { then: inner, .. }
+            | ValidatorRule::Not(inner) => inner.is_sensitive(),
+            ValidatorRule::AnyOf(rules) => rules.iter().any(|rule| rule.is_sensitive()),
+            _ => false,
+        }
+    }
+
+    /// values of the rule available to message templates as ```{value}```, ```{min}``` and ```{max}```
+    fn template_params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ValidatorRule::Length(v)
+            | ValidatorRule::MaxLength(v)
+            | ValidatorRule::MinLength(v)
+            | ValidatorRule::MinWords(v)
+            | ValidatorRule::MaxWords(v)
+            | ValidatorRule::Password(v)
+            | ValidatorRule::MaxDecimalPlaces(v) => {
+                vec![("value", v.to_string())]
+            }
+            ValidatorRule::Size(v)
+            | ValidatorRule::MaxSize(v)
+            | ValidatorRule::MinSize(v)
+            | ValidatorRule::GreaterThan(v)
+            | ValidatorRule::LessThan(v)
+            | ValidatorRule::MultipleOf(v) => vec![("value", v.to_string())],
+            ValidatorRule::MinSizeI64(v) => vec![("value", v.to_string())],
+            ValidatorRule::MaxSizeU64(v) => vec![("value", v.to_string())],
+            ValidatorRule::Contains(v) | ValidatorRule::ContainsIgnoreCase(v) => vec![("value", v.clone())],
+            ValidatorRule::ContainsAll(v)
+            | ValidatorRule::ContainsAny(v)
+            | ValidatorRule::FileExtension(v)
+            | ValidatorRule::Enum(v) => {
+                vec![("value", v.join(", "))]
+            }
+            #[cfg(feature = "regex")]
+            ValidatorRule::MatchesAny(v) => vec![("value", v.join(", "))],
+            ValidatorRule::UuidVersion(v) | ValidatorRule::PasswordStrength(v) => vec![("value", v.to_string())],
+            ValidatorRule::Equals(v)
+            | ValidatorRule::NotEquals(v)
+            | ValidatorRule::EqualsIgnoreCase(v)
+            | ValidatorRule::MatchField(v)
+            | ValidatorRule::DifferentField(v)
+            | ValidatorRule::RequiredWith(v)
+            | ValidatorRule::RequiredWithout(v)
+            | ValidatorRule::ArrayContains(v)
+            | ValidatorRule::PostalCode(v) => vec![("value", v.to_string())],
+            ValidatorRule::PasswordPolicy(policy) => {
+                let mut params = vec![("min", policy.min_length.to_string())];
+                if let Some(max) = policy.max_length {
+                    params.push(("max", max.to_string()));
+                }
+                params
+            }
+            ValidatorRule::LengthRange((min, max)) | ValidatorRule::SizeRange((min, max)) => {
+                vec![("min", min.to_string()), ("max", max.to_string())]
+            }
+            ValidatorRule::LengthBounds((min, max)) => bound_params(min, max),
+            ValidatorRule::SizeBounds((min, max)) => bound_params(min, max),
+            ValidatorRule::FloatBounds((min, max)) => bound_params(min, max),
+            ValidatorRule::WordCountRange((min, max)) | ValidatorRule::ArrayLength((min, max)) => {
+                vec![("min", min.to_string()), ("max", max.to_string())]
+            }
+            ValidatorRule::EditDistance { field, max, min } => {
+                let mut params = vec![("value", field.to_string())];
+                if let Some(min) = min {
+                    params.push(("min", min.to_string()));
+                }
+                if let Some(max) = max {
+                    params.push(("max", max.to_string()));
+                }
+                params
+            }
+            ValidatorRule::Each(inner)
+            | ValidatorRule::Optional(inner)
+            | ValidatorRule::Not(inner)
+            | ValidatorRule::When { then: inner, .. } => inner.template_params(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// values of the bounded ends of a range available to message templates as ```{min}``` and ```{max}```
+fn bound_params<T: fmt::Display>(min: &Bound<T>, max: &Bound<T>) -> Vec<(&'static str, String)> {
+    let mut params = Vec::new();
+    if let Bound::Included(min) | Bound::Excluded(min) = min {
+        params.push(("min", min.to_string()));
+    }
+    if let Bound::Included(max) | Bound::Excluded(max) = max {
+        params.push(("max", max.to_string()));
+    }
+    params
+}
+
+/// renders a user defined message ```template```, replacing ```{field}``` and the ```rule```'s parameters
+fn render_template(template: &str, field: &str, rule: &ValidatorRule) -> String {
+    let mut message = template.replace("{field}", field);
+    for (name, value) in rule.template_params() {
+        message = message.replace(&format!("{{{}}}", name), &value);
+    }
+
+    message
 }
 
 // field and rules to apply
 // type RuleDeclaration = HashMap<String, Vec<RuleType>>;
+#[derive(Debug, Clone)]
 pub struct RuleDeclaration {
     field: String,
     rules: Vec<RuleType>
@@ -51,14 +653,51 @@ impl RuleDeclaration {
         let err = RuleDeclaration::create_err(error);
         RuleDeclaration {
             field: field.to_string(),
-            rules: vec![RuleType(rule, err)]
+            rules: vec![RuleType(rule, err, Severity::Error)]
+        }
+    }
+
+    /// creates a new rule declaration whose rule only reports a [```Warning```] when it fails
+    pub fn warning(field: &str, rule: ValidatorRule, error: Option<&str>) -> RuleDeclaration {
+        let err = RuleDeclaration::create_err(error);
+        RuleDeclaration {
+            field: field.to_string(),
+            rules: vec![RuleType(rule, err, Severity::Warning)]
         }
     }
 
     /// Adds a new rule to declaration 
     pub fn insert(&mut self, rule: ValidatorRule, error: Option<&str>) {
         let err = RuleDeclaration::create_err(error);
-        self.rules.push(RuleType(rule, err));
+        self.rules.push(RuleType(rule, err, Severity::Error));
+    }
+
+    /// Adds a new rule to declaration that only reports a [```Warning```] when it fails
+    pub fn insert_warning(&mut self, rule: ValidatorRule, error: Option<&str>) {
+        let err = RuleDeclaration::create_err(error);
+        self.rules.push(RuleType(rule, err, Severity::Warning));
+    }
+
+    /// Appends the rules of ```other``` to this declaration.
+    /// 
+    /// # Panics
+    /// Panics if ```other``` declares a different field.
+    pub fn merge(&mut self, other: RuleDeclaration) {
+        assert_eq!(self.field, other.field, "cannot merge declarations of different fields");
+        self.rules.extend(other.rules);
+    }
+
+    /// merges declarations of the same field into the first declaration of that field, keeping rule order
+    fn dedup(declarations: Vec<RuleDeclaration>) -> Vec<RuleDeclaration> {
+        let mut merged: Vec<RuleDeclaration> = Vec::new();
+        for decl in declarations {
+            match merged.iter_mut().find(|m| m.field == decl.field) {
+                Some(existing) => existing.merge(decl),
+                None => merged.push(decl),
+            }
+        }
+
+        merged
     }
 
     fn create_err(error: Option<&str>) -> ValidatorErrorType {
@@ -67,172 +706,999 @@ impl RuleDeclaration {
             err = Some(error.to_string());
         }
 
-        return err
+        err
+    }
+}
+
+// rule, error and severity to be associated
+#[derive(Debug, Clone)]
+pub struct RuleType(ValidatorRule, ValidatorErrorType, Severity);
+
+/// Whether a failed rule fails validation or is only reported as a [```Warning```].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// An advisory failure of a rule declared with [```Severity::Warning```]. Warnings don't fail validation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Warning {
+    pub field: String,
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// A single validation failure: a stable, machine-readable ```code``` (e.g. ```too_short```) and its message.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// Errors of each failed field, ordered by field name.
+pub type ValidationErrors = BTreeMap<String, Vec<FieldError>>;
+
+/// Error returned when validation fails. Holds the [```ValidationErrors```] of each failed field.
+/// 
+/// Serializes as an object of each field and its messages.
+#[derive(Debug, Clone)]
+pub struct ValidationError(pub ValidationErrors);
+
+impl ValidationError {
+    /// HTTP status code of a failed validation: ```422 Unprocessable Entity```.
+    pub const STATUS_CODE: u16 = 422;
+
+    /// Returns the HTTP response body sent by the web framework integrations, with the code and message of each
+    /// error: ```{ "status": 422, "errors": { "field": [{ "code": "too_short", "message": "..." }] } }```.
+    pub fn into_response_body(self) -> Value {
+        let errors: Map<String, Value> = self
+            .0
+            .into_iter()
+            .map(|(field, errors)| {
+                let errors = errors
+                    .into_iter()
+                    .map(|FieldError { code, message }| serde_json::json!({ "code": code, "message": message }))
+                    .collect();
+                (field, Value::Array(errors))
+            })
+            .collect();
+
+        serde_json::json!({ "status": ValidationError::STATUS_CODE, "errors": errors })
+    }
+
+    /// Returns only the messages of each failed field.
+    pub fn messages(&self) -> BTreeMap<String, Vec<String>> {
+        self.0
+            .iter()
+            .map(|(field, errors)| (field.clone(), errors.iter().map(|e| e.message.clone()).collect()))
+            .collect()
+    }
+
+    /// Returns whether any field failed.
+    pub fn has_errors(&self) -> bool {
+        self.0.values().any(|errors| !errors.is_empty())
+    }
+
+    /// Returns the messages of ```field```, or ```None``` if it didn't fail.
+    pub fn field_errors(&self, field: &str) -> Option<Vec<&str>> {
+        self.0.get(field).map(|errors| errors.iter().map(|e| e.message.as_str()).collect())
+    }
+
+    /// Returns the number of messages across all fields.
+    pub fn error_count(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns the first message of the first failed field (fields are ordered by name).
+    pub fn first_error(&self) -> Option<&str> {
+        self.0.values().flatten().next().map(|e| e.message.as_str())
+    }
+
+    /// Keeps only the first error of each field, e.g. for UIs that show one message per field at a time. Keep a
+    /// clone of the full errors if they're needed for debugging.
+    pub fn first_per_field(mut self) -> ValidationError {
+        for errors in self.0.values_mut() {
+            errors.truncate(1);
+        }
+
+        self
+    }
+
+    /// Returns the errors as a JSON object of each field and its messages: ```{ "field": ["msg1", "msg2"] }```.
+    pub fn to_json(&self) -> Value {
+        Value::Object(
+            self.messages()
+                .into_iter()
+                .map(|(field, messages)| (field, Value::from(messages)))
+                .collect(),
+        )
+    }
+}
+
+impl Serialize for ValidationError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.messages().serialize(serializer)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (field, messages)) in self.messages().iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", field, messages.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Implemented by types that validate themselves against their own rules, usually through ```#[derive(Validate)]```:
+/// 
+/// ```
+/// use freeval::Validate;
+/// 
+/// #[derive(serde::Serialize, Validate)]
+/// struct RequestData {
+///     #[validate(length(min = 8, max = 12, message = "username must be between 8 and 12"), required)]
+///     username: String,
+///     #[validate(password(min = 8))]
+///     password: String,
+/// }
+/// 
+/// let data = RequestData { username: "Olamide".to_string(), password: "myWeakPass".to_string() };
+/// assert!(data.validate().is_err());
+/// ```
+pub trait Validate {
+    /// validates self against its declared rules
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// evaluates a single ```rule``` against the ```val``` of field ```key```. ```map``` is the whole validated object.
+fn evaluate(key: &str, rule: &ValidatorRule, val: Value, map: &Map<String, Value>) -> InnerValidationResult {
+    match rule {
+        ValidatorRule::Length(rule) => length(key, rule, val, LengthType::Exact),
+        ValidatorRule::MaxLength(rule) => length(key, rule, val, LengthType::Max),
+        ValidatorRule::MinLength(rule) => length(key, rule, val, LengthType::Min),
+        ValidatorRule::Size(rule) => size(key, rule, val, LengthType::Exact),
+        ValidatorRule::MaxSize(rule) => size(key, rule, val, LengthType::Max),
+        ValidatorRule::MinSize(rule) => size(key, rule, val, LengthType::Min),
+        ValidatorRule::MinSizeI64(rule) => min_size_i64(key, rule, val),
+        ValidatorRule::MaxSizeU64(rule) => max_size_u64(key, rule, val),
+        ValidatorRule::GreaterThan(rule) => greater_than(key, rule, val),
+        ValidatorRule::LessThan(rule) => less_than(key, rule, val),
+        ValidatorRule::Even => even(key, val),
+        ValidatorRule::Odd => odd(key, val),
+        ValidatorRule::MultipleOf(rule) => multiple_of(key, rule, val),
+        ValidatorRule::StepFrom { base, step } => step_from(key, *base, *step, val),
+        ValidatorRule::Bool => check_bool(key, val),
+        ValidatorRule::MustBeTrue => must_be_true(key, val),
+        ValidatorRule::MustBeFalse => must_be_false(key, val),
+        ValidatorRule::Password(min_len) => password(key, val, *min_len),
+        ValidatorRule::PasswordPolicy(policy) => password_policy(key, val, policy),
+        ValidatorRule::PasswordStrength(min) => min_password_strength(key, val, *min),
+        ValidatorRule::Required => required(key, val),
+        #[cfg(feature = "regex")]
+        ValidatorRule::Email => email(key, val),
+        #[cfg(feature = "regex")]
+        ValidatorRule::MatchesAny(patterns) => matches_any(key, patterns, val),
+        ValidatorRule::LengthRange((min,max)) => range(key, val, min, max, RangeType::Length),
+        ValidatorRule::SizeRange((min, max)) => range(key, val, min, max, RangeType::Size),
+        ValidatorRule::LengthBounds((min, max)) => length_bounds(key, val, min, max),
+        ValidatorRule::SizeBounds((min, max)) => size_bounds(key, val, min, max),
+        ValidatorRule::FloatBounds((min, max)) => float_bounds(key, val, min, max),
+        ValidatorRule::FloatPositive => float_positive(key, val),
+        ValidatorRule::FloatNegative => float_negative(key, val),
+        ValidatorRule::FloatNonZero => float_non_zero(key, val),
+        ValidatorRule::Contains(rule) => contains(key, rule, val),
+        ValidatorRule::ContainsIgnoreCase(rule) => contains_ignore_case(key, rule, val),
+        ValidatorRule::ContainsAll(rule) => contains_all(key, rule, val),
+        ValidatorRule::ContainsAny(rule) => contains_any(key, rule, val),
+        ValidatorRule::NoWhitespace => no_whitespace(key, val),
+        ValidatorRule::Lowercase => is_lowercase(key, val),
+        ValidatorRule::Uppercase => is_uppercase(key, val),
+        ValidatorRule::Trimmed => trimmed(key, val),
+        ValidatorRule::NonEmpty => non_empty(key, val),
+        ValidatorRule::WordCountRange((min, max)) => word_count(key, val, min, max),
+        ValidatorRule::MinWords(rule) => min_words(key, rule, val),
+        ValidatorRule::MaxWords(rule) => max_words(key, rule, val),
+        ValidatorRule::SingleLine => single_line(key, val),
+        #[cfg(feature = "regex")]
+        ValidatorRule::DateTime => datetime(key, val),
+        #[cfg(feature = "regex")]
+        ValidatorRule::Date => date(key, val),
+        ValidatorRule::Time => time(key, val),
+        ValidatorRule::Timezone => timezone(key, val),
+        ValidatorRule::Cron => cron(key, val),
+        ValidatorRule::CurrencyCode => currency_code(key, val),
+        ValidatorRule::CountryCode => country_code(key, val),
+        ValidatorRule::PostalCode(country) => postal_code(key, country, val),
+        ValidatorRule::VatNumber => vat_number(key, val),
+        ValidatorRule::Iban => iban(key, val),
+        ValidatorRule::Port => port(key, val),
+        ValidatorRule::Hostname => hostname(key, val),
+        ValidatorRule::Ean13 => ean13(key, val),
+        ValidatorRule::UpcA => upc_a(key, val),
+        ValidatorRule::Isbn10 => isbn10(key, val),
+        ValidatorRule::Isbn13 => isbn13(key, val),
+        ValidatorRule::Ulid => ulid(key, val),
+        ValidatorRule::UuidVersion(version) => uuid_version(key, version, val),
+        ValidatorRule::Ascii => ascii(key, val),
+        ValidatorRule::AsciiPrintable => ascii_printable(key, val),
+        ValidatorRule::Luhn => luhn(key, val),
+        ValidatorRule::MaxDecimalPlaces(rule) => max_decimal_places(key, rule, val),
+        ValidatorRule::JsonArray => json_array(key, val),
+        ValidatorRule::JsonObject => json_object(key, val),
+        ValidatorRule::ArrayLength((min, max)) => array_length(key, val, min, max),
+        ValidatorRule::UniqueItems => unique_items(key, val),
+        ValidatorRule::ArrayContains(rule) => array_contains(key, rule, val),
+        ValidatorRule::Domain => domain(key, val),
+        ValidatorRule::HttpUrl => http_url(key, val),
+        ValidatorRule::CssColor => css_color(key, val),
+        ValidatorRule::FileExtension(rule) => file_extension(key, rule, val),
+        ValidatorRule::MimeType => mime_type(key, val),
+        ValidatorRule::Equals(rule) => equals(key, rule, val),
+        ValidatorRule::NotEquals(rule) => not_equals(key, rule, val),
+        ValidatorRule::EqualsIgnoreCase(rule) => equals_ignore_case(key, rule, val),
+        ValidatorRule::Enum(variants) => enum_variant(key, variants, val),
+        ValidatorRule::DependsOn { fields, check } => depends_on(key, val, fields, *check, map),
+        ValidatorRule::SizeLimitByMime { mime_field, limits } => size_limit_by_mime(key, val, mime_field, limits, map),
+        ValidatorRule::MatchField(other) => match_field(key, val, other, map),
+        ValidatorRule::DifferentField(other) => different_field(key, val, other, map),
+        ValidatorRule::RequiredWith(other) => required_with(key, val, other, map),
+        ValidatorRule::RequiredWithout(other) => required_without(key, val, other, map),
+        ValidatorRule::EditDistance { field, max, min } => edit_distance(key, val, field, *min, *max, map),
+        ValidatorRule::Boxed(validator) => validator.validate(key, &val),
+        #[cfg(feature = "async")]
+        ValidatorRule::Async(_) => InnerValidationResult(false, format!("'{}' field requires async validation.", key)),
+        ValidatorRule::Each(_) => {
+            let mut failures = check(key, rule, val, map);
+            if failures.is_empty() {
+                return InnerValidationResult(true, String::new());
+            }
+
+            let (_, err) = failures.remove(0);
+            InnerValidationResult(false, err.message)
+        }
+        ValidatorRule::Optional(inner) => {
+            if val.is_null() {
+                return InnerValidationResult(true, String::new());
+            }
+
+            evaluate(key, inner, val, map)
+        }
+        ValidatorRule::When { field, equals, then } => {
+            if !field_equals(field, equals, map) {
+                return InnerValidationResult(true, String::new());
+            }
+
+            evaluate(key, then, val, map)
+        }
+        ValidatorRule::AnyOf(rules) => {
+            let mut errors = Vec::new();
+            for rule in rules {
+                let InnerValidationResult(status, err) = evaluate(key, rule, val.clone(), map);
+                if status {
+                    return InnerValidationResult(true, String::new());
+                }
+
+                errors.push(err);
+            }
+
+            InnerValidationResult(false, errors.join(" or "))
+        }
+        ValidatorRule::Not(inner) => {
+            let InnerValidationResult(status, err) = evaluate(key, inner, val, map);
+            InnerValidationResult(!status, format!("'{}' field must not satisfy: {}", key, err))
+        }
     }
 }
 
-// rule and error to be associated
-pub struct RuleType(ValidatorRule, ValidatorErrorType);
+/// checks ```rule``` against the ```val``` of field ```key```, returning the (possibly indexed) key and default
+/// error of each failure.
+fn check(key: &str, rule: &ValidatorRule, val: Value, map: &Map<String, Value>) -> Vec<(String, FieldError)> {
+    match rule {
+        ValidatorRule::Each(inner) => match val {
+            Value::Array(items) => items
+                .into_iter()
+                .enumerate()
+                .flat_map(|(i, item)| check(&format!("{}[{}]", key, i), inner, item, map))
+                .collect(),
+            _ => {
+                let message = format!("'{}' field must be an array.", key);
+                vec![(key.to_string(), FieldError { code: "not_array", message })]
+            }
+        },
+        ValidatorRule::ArrayLength(_) | ValidatorRule::UniqueItems | ValidatorRule::ArrayContains(_) if !val.is_array() => {
+            let message = format!("'{}' field must be an array.", key);
+            vec![(key.to_string(), FieldError { code: "not_array", message })]
+        }
+        ValidatorRule::Optional(inner) => {
+            if val.is_null() {
+                return Vec::new();
+            }
+
+            check(key, inner, val, map)
+        }
+        ValidatorRule::When { field, equals, then } => {
+            if !field_equals(field, equals, map) {
+                return Vec::new();
+            }
+
+            check(key, then, val, map)
+        }
+        _ => {
+            let InnerValidationResult(status, message) = evaluate(key, rule, val, map);
+            if status {
+                return Vec::new();
+            }
+
+            vec![(key.to_string(), FieldError { code: rule.code(), message })]
+        }
+    }
+}
 
-pub type ValidationErrors = HashMap<String, Vec<String>>;
+/// settings applied while validating
+#[derive(Debug, Clone, Copy, Default)]
+struct Options {
+    /// stop at the first failure
+    fail_fast: bool,
+    /// language of default messages
+    locale: Locale,
+    /// append the received value to default messages
+    show_values: bool,
+    /// warn about declarations whose field isn't in the data
+    warn_unmatched: bool,
+    /// fail declarations whose field isn't in the data
+    strict: bool,
+}
 
+#[derive(Debug)]
 pub struct FreeVal<'a, T: Serialize> {
     pub data: &'a T,
     pub declarations: Vec<RuleDeclaration>,
+    options: Options,
+}
+
+// derived Clone would require T: Clone, but only the reference to data is cloned
+impl<T: Serialize> Clone for FreeVal<'_, T> {
+    fn clone(&self) -> Self {
+        FreeVal { data: self.data, declarations: self.declarations.clone(), options: self.options }
+    }
 }
 
 impl<'a, T: Serialize> FreeVal<'a, T> {
+    /// Creates a validator of ```data```. Declarations of the same field are merged, so all their rules apply.
     pub fn new(data: &'a T, declarations: Vec<RuleDeclaration>) -> FreeVal<'a, T> {
-        FreeVal { data, declarations }
+        let declarations = RuleDeclaration::dedup(declarations);
+        FreeVal { data, declarations, options: Options::default() }
     }
 
-    pub fn validate(&self) -> Result<(), ValidationErrors> {
-        let mut result_errs = HashMap::new();
+    /// Creates a [```FreeValBuilder```] to declare the rules of ```data``` incrementally.
+    pub fn builder(data: &'a T) -> FreeValBuilder<'a, T> {
+        FreeValBuilder { data, declarations: Vec::new(), options: Options::default() }
+    }
 
-        if let Ok(serde_json::Value::Object(map)) = serde_json::to_value(self.data) {
-            // iterate of keys/values of validator data...
-            for (key, value) in &map {
-                // ...then iterate over rule declarations to get field's rules
-                for decl in &self.declarations {
-                    if &decl.field == key {
-                        // ...then iterate over each rule to validate
-                        for rule_type in &decl.rules {
-                            let mut _inner_result = InnerValidationResult(false, String::new());
-    
-                            let rule = &rule_type.0;
-                            let error = &rule_type.1;
-                            let val = value.clone();
-                            
-                            match rule {
-                                ValidatorRule::Length(rule) => _inner_result = length(key, &rule, val, LengthType::Exact),
-                                ValidatorRule::MaxLength(rule) => _inner_result = length(key, &rule, val, LengthType::Max),
-                                ValidatorRule::MinLength(rule) => _inner_result = length(key, &rule, val, LengthType::Min),
-                                ValidatorRule::Size(rule) => _inner_result = size(key, &rule, val, LengthType::Exact),
-                                ValidatorRule::MaxSize(rule) => _inner_result = size(key, &rule, val, LengthType::Max),
-                                ValidatorRule::MinSize(rule) => _inner_result = size(key, &rule, val, LengthType::Min),
-                                ValidatorRule::Bool => _inner_result = check_bool(key, val),
-                                ValidatorRule::Password(min_len) => _inner_result = password(key, val, *min_len),
-                                ValidatorRule::Required => _inner_result = required(key, val),
-                                ValidatorRule::Email => _inner_result = email(key, val),
-                                ValidatorRule::LengthRange((min,max)) => _inner_result = range(key, val, min, max, RangeType::Length),
-                                ValidatorRule::SizeRange((min, max)) => _inner_result = range(key, val, min, max, RangeType::Size),
-                                ValidatorRule::Contains(rule) => _inner_result = contains(key, *rule, val)
-                            }
-    
-                            let InnerValidationResult(status, default_err) = _inner_result;
-                            if !status {
-                                // Initialize field errors if it does not exist.
-                                if let None = result_errs.get(key) {
-                                    result_errs.insert(key.to_string(), Vec::new());
-                                }
-    
-                                if let Some(error_list) = result_errs.get(key) {
-                                    let errors = self.add_error(error, default_err, error_list);
-                                    result_errs.insert(key.to_string(), errors);
-                                }
-                            }
-                
-                        }
-                    
-                    }
-                }
-            }
-        }
+    /// Sets the [```Locale```] of default error messages.
+    pub fn with_locale(mut self, locale: Locale) -> FreeVal<'a, T> {
+        self.options.locale = locale;
+        self
+    }
 
-        if !result_errs.is_empty() {
-            return Err(result_errs);
-        }
+    /// Appends the received value to default error messages, e.g. ```'age' field must be minimum of 18 (got 12).```
+    /// Off by default for privacy. Fields with a password rule never show their value under any of their rules, nor do
+    /// elements checked by ```Each```.
+    pub fn show_values(mut self, show: bool) -> FreeVal<'a, T> {
+        self.options.show_values = show;
+        self
+    }
 
-        Ok(())
+    /// Reports a [```Warning```] with code ```unmatched_field``` for each declaration whose field isn't a key of
+    /// the serialized data, e.g. a typo or a Rust field name renamed by serde. Meant for debugging; the warnings
+    /// are returned by [```FreeVal::validate_with_warnings```].
+    pub fn warn_unmatched(mut self, warn: bool) -> FreeVal<'a, T> {
+        self.options.warn_unmatched = warn;
+        self
     }
 
-    /// adds an error to ```error_list```.
+    /// Validates data against the declared rules.
+    /// 
+    /// Declared fields are matched against the keys of the serialized data, so a field renamed with
+    /// ```#[serde(rename = "userName")]``` must be declared as ```"userName"```. The ```Validate``` derive
+    /// does this for you. See [```FreeVal::warn_unmatched```] to catch declarations that match nothing.
     /// 
-    /// Checks if there's a user ```defined_err``` and if there's none, adds the ```default_err```.
+    /// Declared fields may be dotted paths (```"address.city"```) to target fields of nested structs. If a key
+    /// on the path doesn't exist the declaration is skipped, while a ```null``` intermediate value (e.g. a ```None```
+    /// struct) is validated as ```null```.
     /// 
-    /// Returns the new ```error_list```. 
-    fn add_error(&self, defined_err: &ValidatorErrorType, default_err: String, error_list: &Vec<String>) -> Vec<String> {
-        let mut error = default_err;
+    /// Rules of a field run in declaration order and all failures are reported, except that a failed
+    /// ```ValidatorRule::Required``` stops the remaining rules of its field.
+    /// 
+    /// ```ValidatorRule::Async``` rules can't run here and always fail; use [```FreeVal::validate_async```] instead.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_declarations(self.data, &self.declarations, self.options).map(|_| ())
+    }
+
+    /// Validates data like [```FreeVal::validate```], also awaiting the ```ValidatorRule::Async``` rules of the
+    /// fields present in data, including those nested in other rules. Failed async rules are reported like any other
+    /// rule, e.g. a failed warning rule doesn't fail validation.
+    #[cfg(feature = "async")]
+    pub async fn validate_async(&self) -> Result<(), ValidationError> {
+        validate_declarations_async(self.data, &self.declarations, self.options).await
+    }
+
+    /// Validates data like [```FreeVal::validate```], but also fails each declared field that isn't a key of the
+    /// serialized data with an ```unknown_field``` error. Useful in tests to catch typos in field names.
+    pub fn validate_strict(&self) -> Result<(), ValidationError> {
+        let options = Options { strict: true, ..self.options };
+        validate_declarations(self.data, &self.declarations, options).map(|_| ())
+    }
+
+    /// Validates data like [```FreeVal::validate```], also returning the [```Warning```]s of failed warning rules
+    /// when validation succeeds.
+    pub fn validate_with_warnings(&self) -> Result<Vec<Warning>, ValidationError> {
+        validate_declarations(self.data, &self.declarations, self.options)
+    }
+
+    /// Validates data like [```FreeVal::validate```] but stops at the first failed rule, returning its field and error.
+    pub fn validate_fast(&self) -> Result<(), (String, String)> {
+        validate_fast(self.data, &self.declarations, self.options)
+    }
 
-        if let Some(err) = defined_err {
-            error = err.to_string();
+    /// Returns whether data passes all declared rules. Validation stops at the first failed rule.
+    pub fn is_valid(&self) -> bool {
+        self.validate_fast().is_ok()
+    }
+
+    /// Validates only the declarations of ```field```, returning its errors.
+    pub fn validate_field(&self, field: &str) -> Result<(), Vec<String>> {
+        validate_field(self.data, &self.declarations, field, self.options)
+    }
+}
+
+/// Builds a [```FreeVal```] one rule at a time:
+/// 
+/// ```
+/// use freeval::*;
+/// 
+/// #[derive(serde::Serialize)]
+/// struct RequestData {
+///     username: &'static str,
+/// }
+/// 
+/// let data = RequestData { username: "Olamide" };
+/// let validator = FreeVal::builder(&data)
+///     .rule("username", ValidatorRule::Required, None)
+///     .rule("username", ValidatorRule::MinLength(8), Some("username is too short"))
+///     .build();
+/// 
+/// assert!(validator.validate().is_err());
+/// ```
+pub struct FreeValBuilder<'a, T: Serialize> {
+    data: &'a T,
+    declarations: Vec<RuleDeclaration>,
+    options: Options,
+}
+
+impl<'a, T: Serialize> FreeValBuilder<'a, T> {
+    /// Adds a rule with an optional error message to ```field```. Rules of the same field are kept in one declaration.
+    pub fn rule(mut self, field: &str, rule: ValidatorRule, error: Option<&str>) -> FreeValBuilder<'a, T> {
+        match self.declarations.iter_mut().find(|decl| decl.field == field) {
+            Some(decl) => decl.insert(rule, error),
+            None => self.declarations.push(RuleDeclaration::new(field, rule, error)),
         }
 
-        let mut errors = error_list.clone().to_vec();
-        errors.push(error);
+        self
+    }
+
+    /// Sets the [```Locale```] of default error messages.
+    pub fn locale(mut self, locale: Locale) -> FreeValBuilder<'a, T> {
+        self.options.locale = locale;
+        self
+    }
+
+    /// Appends the received value to default error messages. See [```FreeVal::show_values```].
+    pub fn show_values(mut self, show: bool) -> FreeValBuilder<'a, T> {
+        self.options.show_values = show;
+        self
+    }
+
+    /// Warns about declarations whose field isn't in the data. See [```FreeVal::warn_unmatched```].
+    pub fn warn_unmatched(mut self, warn: bool) -> FreeValBuilder<'a, T> {
+        self.options.warn_unmatched = warn;
+        self
+    }
 
-        return errors;
+    /// Creates the [```FreeVal```] with the declared rules.
+    pub fn build(self) -> FreeVal<'a, T> {
+        FreeVal { data: self.data, declarations: self.declarations, options: self.options }
     }
 }
 
-#[derive(Serialize)]
-struct DemoStruct {
-    name: &'static str,
-    city: &'static str,
-    age: u8,
-    bio: Option<String>,
-    allow: bool,
-    password: &'static str,
-    email: &'static str,
+/// A reusable set of rule declarations. Unlike [```FreeVal```], it isn't tied to a single input so the same rules
+/// can validate many inputs.
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    pub declarations: Vec<RuleDeclaration>,
+    options: Options,
 }
 
-#[derive(serde::Serialize)]
-struct RequestData{
-    username: &'static str,
-    password: &'static str
-} 
+impl RuleSet {
+    /// Creates a rule set. Declarations of the same field are merged, so all their rules apply.
+    pub fn new(declarations: Vec<RuleDeclaration>) -> RuleSet {
+        let declarations = RuleDeclaration::dedup(declarations);
+        RuleSet { declarations, options: Options::default() }
+    }
 
+    /// Sets the [```Locale```] of default error messages.
+    pub fn with_locale(mut self, locale: Locale) -> RuleSet {
+        self.options.locale = locale;
+        self
+    }
 
-#[cfg(test)]
-mod tests {
+    /// Appends the received value to default error messages. See [```FreeVal::show_values```].
+    pub fn show_values(mut self, show: bool) -> RuleSet {
+        self.options.show_values = show;
+        self
+    }
 
-    #[test]
-    fn test_validator() {
-        use super::*;
+    /// Warns about declarations whose field isn't in the data. See [```FreeVal::warn_unmatched```].
+    pub fn warn_unmatched(mut self, warn: bool) -> RuleSet {
+        self.options.warn_unmatched = warn;
+        self
+    }
 
-        let demo = DemoStruct {
-            name: "Olamide",
-            city: "Nigeria",
-            age: 36,
-            bio: None,
-            allow: true,
-            password: "WhatAPass@003",
-            email: "myemail@gmailcom"
-        };
+    /// Validates ```data``` against the declared rules. See [```FreeVal::validate```].
+    pub fn validate<T: Serialize>(&self, data: &T) -> Result<(), ValidationError> {
+        validate_declarations(data, &self.declarations, self.options).map(|_| ())
+    }
 
-        // declare validation rules for any field you wish to validate
-        let name_rule = declare_rule!("name", ValidatorRule::Length(12));
-        let age_rule = declare_rule!("age", ValidatorRule::Size(18), "You're under-aged!");
+    /// Validates ```data``` and fails declared fields missing from it. See [```FreeVal::validate_strict```].
+    pub fn validate_strict<T: Serialize>(&self, data: &T) -> Result<(), ValidationError> {
+        let options = Options { strict: true, ..self.options };
+        validate_declarations(data, &self.declarations, options).map(|_| ())
+    }
 
-        let mut bio_rule = declare_rule!("bio", ValidatorRule::Required);
-        insert_rule!(bio_rule, ValidatorRule::MinLength(12), "Bio is too short!"); // We can add more validation rules to a single field
+    /// Validates every element of ```items```, returning the index and errors of each failed element. An empty
+    /// result means all elements are valid.
+    pub fn validate_batch<T: Serialize>(&self, items: &[T]) -> Vec<(usize, ValidationError)> {
+        items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| self.validate(item).err().map(|err| (i, err)))
+            .collect()
+    }
 
-        let allow_rule = declare_rule!("allow", ValidatorRule::Bool);
-        let pass_rule = declare_rule!("password", ValidatorRule::Password(8), "Password is incorrect");
-        let email_rule = declare_rule!("email", ValidatorRule::Email);
+    /// Validates ```data``` and returns its warnings. See [```FreeVal::validate_with_warnings```].
+    pub fn validate_with_warnings<T: Serialize>(&self, data: &T) -> Result<Vec<Warning>, ValidationError> {
+        validate_declarations(data, &self.declarations, self.options)
+    }
 
-        // create your validator with declarations
-        let val = freeval!(
-            &demo,
-            vec![name_rule, age_rule, bio_rule, allow_rule, pass_rule, email_rule]
-        );
+    /// Validates ```data``` but stops at the first failed rule. See [```FreeVal::validate_fast```].
+    pub fn validate_fast<T: Serialize>(&self, data: &T) -> Result<(), (String, String)> {
+        validate_fast(data, &self.declarations, self.options)
+    }
 
-        let result = val.validate();
-        // if let Err(e) = &result {
-        //     println!("errors {:?}", e);
-        // }
-        
-        assert!(result.is_err())
+    /// Returns whether ```data``` passes all declared rules. See [```FreeVal::is_valid```].
+    pub fn is_valid<T: Serialize>(&self, data: &T) -> bool {
+        self.validate_fast(data).is_ok()
     }
 
-    #[test]
-    fn demo_test() {
-        use super::*;
+    /// Validates only the declarations of ```field``` in ```data```. See [```FreeVal::validate_field```].
+    pub fn validate_field<T: Serialize>(&self, data: &T, field: &str) -> Result<(), Vec<String>> {
+        validate_field(data, &self.declarations, field, self.options)
+    }
+}
 
-        let data = RequestData {
-            username: "Olamide",
-            password: "myWeakPass"
-        };
+/// validates ```data``` against ```declarations```, returning the field and error of the first failure
+fn validate_fast<T: Serialize>(data: &T, declarations: &[RuleDeclaration], options: Options) -> Result<(), (String, String)> {
+    let options = Options { fail_fast: true, ..options };
+    if let Err(ValidationError(errs)) = validate_declarations(data, declarations, options) {
+        if let Some((field, mut errors)) = errs.into_iter().next() {
+            return Err((field, errors.remove(0).message));
+        }
+    }
+
+    Ok(())
+}
+
+/// validates ```data``` against the declarations of ```field```, returning its errors
+fn validate_field<T: Serialize>(
+    data: &T,
+    declarations: &[RuleDeclaration],
+    field: &str,
+    options: Options,
+) -> Result<(), Vec<String>> {
+    let declarations = declarations.iter().filter(|decl| decl.field == field);
+
+    if let Err(ValidationError(errs)) = validate_declarations(data, declarations, options) {
+        return Err(errs.into_values().flatten().map(|e| e.message).collect());
+    }
+
+    Ok(())
+}
+
+/// Validates an already parsed JSON ```value``` (e.g. a request body) against ```declarations```, without requiring
+/// a [```Serialize```] struct. Like [```FreeVal::validate```], values that aren't objects have no fields to validate.
+/// 
+/// ```
+/// use freeval::*;
+/// 
+/// let body = serde_json::json!({ "username": "Olamide" });
+/// let result = validate_value(&body, &[declare_rule!("username", ValidatorRule::MinLength(8))]);
+/// 
+/// assert!(result.is_err());
+/// ```
+pub fn validate_value(value: &Value, declarations: &[RuleDeclaration]) -> Result<(), ValidationError> {
+    match value {
+        Value::Object(map) => validate_map(map, declarations, Options::default(), AwaitedFailures::new()).map(|_| ()),
+        _ => Ok(()),
+    }
+}
+
+/// validates ```data``` against ```declarations``` with the given ```options```, returning the warnings on success
+fn validate_declarations<'r, T: Serialize>(
+    data: &T,
+    declarations: impl IntoIterator<Item = &'r RuleDeclaration>,
+    options: Options,
+) -> Result<Vec<Warning>, ValidationError> {
+    match serde_json::to_value(data) {
+        Ok(Value::Object(map)) => validate_map(&map, declarations, options, AwaitedFailures::new()),
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// default message of a failed ```ValidatorRule::Async``` rule
+#[cfg(feature = "async")]
+fn async_message(field: &str) -> String {
+    format!("'{}' field is invalid.", field)
+}
+
+/// validates ```data``` like [```validate_declarations```], awaiting the async rules of ```declarations```
+#[cfg(feature = "async")]
+async fn validate_declarations_async<T: Serialize>(
+    data: &T,
+    declarations: &[RuleDeclaration],
+    options: Options,
+) -> Result<(), ValidationError> {
+    let map = match serde_json::to_value(data) {
+        Ok(Value::Object(map)) => map,
+        _ => return Ok(()),
+    };
+
+    // await the rules containing async checks first, so their failures are reported like any other rule's
+    let mut awaited = AwaitedFailures::new();
+    for (decl_index, decl) in declarations.iter().enumerate() {
+        let value = match lookup(&map, &decl.field) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        for (rule_index, RuleType(rule, _, severity)) in decl.rules.iter().enumerate() {
+            // like validate_map, stop at a failed required error
+            if value.is_null() && *severity == Severity::Error && matches!(rule, ValidatorRule::Required) {
+                break;
+            }
+
+            if rule.is_async() {
+                let failures = check_async(decl.field.clone(), rule, value.clone(), &map).await;
+                awaited.insert((decl_index, rule_index), failures);
+            }
+        }
+    }
+
+    validate_map(&map, declarations, options, awaited).map(|_| ())
+}
+
+/// future of [```check_async```] and [```evaluate_async```]
+#[cfg(feature = "async")]
+type LocalFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// like [```check```], but awaits the ```ValidatorRule::Async``` rules nested in ```rule```
+#[cfg(feature = "async")]
+fn check_async<'a>(
+    key: String,
+    rule: &'a ValidatorRule,
+    val: Value,
+    map: &'a Map<String, Value>,
+) -> LocalFuture<'a, Vec<(String, FieldError)>> {
+    Box::pin(async move {
+        if !rule.is_async() {
+            return check(&key, rule, val, map);
+        }
+
+        match rule {
+            ValidatorRule::Each(inner) => match val {
+                Value::Array(items) => {
+                    let mut failures = Vec::new();
+                    for (i, item) in items.into_iter().enumerate() {
+                        failures.extend(check_async(format!("{}[{}]", key, i), inner, item, map).await);
+                    }
+
+                    failures
+                }
+                _ => check(&key, rule, val, map),
+            },
+            ValidatorRule::Optional(inner) => {
+                if val.is_null() {
+                    return Vec::new();
+                }
+
+                check_async(key, inner, val, map).await
+            }
+            ValidatorRule::When { field, equals, then } => {
+                if !field_equals(field, equals, map) {
+                    return Vec::new();
+                }
+
+                check_async(key, then, val, map).await
+            }
+            _ => {
+                let InnerValidationResult(status, message) = evaluate_async(&key, rule, val, map).await;
+                if status {
+                    return Vec::new();
+                }
+
+                vec![(key, FieldError { code: rule.code(), message })]
+            }
+        }
+    })
+}
+
+/// like [```evaluate```], but awaits the ```ValidatorRule::Async``` rules nested in ```rule```
+#[cfg(feature = "async")]
+fn evaluate_async<'a>(
+    key: &'a str,
+    rule: &'a ValidatorRule,
+    val: Value,
+    map: &'a Map<String, Value>,
+) -> LocalFuture<'a, InnerValidationResult> {
+    Box::pin(async move {
+        match rule {
+            ValidatorRule::Async(check) => InnerValidationResult((check.0)(val).await, async_message(key)),
+            ValidatorRule::Each(_) | ValidatorRule::Optional(_) | ValidatorRule::When { .. } => {
+                match check_async(key.to_string(), rule, val, map).await.into_iter().next() {
+                    Some((_, err)) => InnerValidationResult(false, err.message),
+                    None => InnerValidationResult(true, String::new()),
+                }
+            }
+            ValidatorRule::AnyOf(rules) => {
+                let mut errors = Vec::new();
+                for rule in rules {
+                    let InnerValidationResult(status, err) = evaluate_async(key, rule, val.clone(), map).await;
+                    if status {
+                        return InnerValidationResult(true, String::new());
+                    }
+
+                    errors.push(err);
+                }
+
+                InnerValidationResult(false, errors.join(" or "))
+            }
+            ValidatorRule::Not(inner) => {
+                let InnerValidationResult(status, err) = evaluate_async(key, inner, val, map).await;
+                InnerValidationResult(!status, format!("'{}' field must not satisfy: {}", key, err))
+            }
+            _ => evaluate(key, rule, val, map),
+        }
+    })
+}
+
+/// failures of async rules, awaited before validation, by index of declaration and of rule in the declaration
+type AwaitedFailures = BTreeMap<(usize, usize), Vec<(String, FieldError)>>;
+
+/// validates the fields of ```map``` against ```declarations``` with the given ```options```, returning the
+/// warnings on success. Rules with ```awaited``` failures report those instead of being checked.
+fn validate_map<'r>(
+    map: &Map<String, Value>,
+    declarations: impl IntoIterator<Item = &'r RuleDeclaration>,
+    options: Options,
+    mut awaited: AwaitedFailures,
+) -> Result<Vec<Warning>, ValidationError> {
+    let mut result_errs = BTreeMap::new();
+    let mut warnings = Vec::new();
+
+    // iterate over rule declarations...
+    for (decl_index, decl) in declarations.into_iter().enumerate() {
+        // ...then resolve the value of the declared field...
+        let key = &decl.field;
+        let found = lookup(map, key);
+        let value = found.unwrap_or(&Value::Null);
+
+        if found.is_none() && options.strict {
+            let message = format!("'{}' field is not in the data.", key);
+            result_errs.entry(key.clone()).or_insert_with(Vec::new).push(FieldError { code: "unknown_field", message });
+
+            if options.fail_fast {
+                return Err(ValidationError(result_errs));
+            }
+        } else if found.is_none() && options.warn_unmatched {
+            let message = format!("'{}' field doesn't match any key of the data.", key);
+            warnings.push(Warning { field: key.clone(), code: "unmatched_field", message });
+        }
+
+        // a field checked by any password rule never shows its value, whichever of its rules fails
+        let sensitive = decl.rules.iter().any(|rule_type| rule_type.0.is_sensitive());
+
+        // ...then iterate over each rule to validate
+        for (rule_index, rule_type) in decl.rules.iter().enumerate() {
+            let rule = &rule_type.0;
+            let error = &rule_type.1;
+            let severity = rule_type.2;
+
+            // missing fields are skipped, except by rules that depend on other fields being present
+            if found.is_none() && !matches!(rule, ValidatorRule::RequiredWith(_) | ValidatorRule::RequiredWithout(_)) {
+                continue;
+            }
+
+            let failures = match awaited.remove(&(decl_index, rule_index)) {
+                Some(failures) => failures,
+                None => check(key, rule, value.clone(), map),
+            };
+
+            // a null required field fails only with the required error: its other rules can't pass on null
+            let short_circuit = !failures.is_empty() && severity == Severity::Error && matches!(rule, ValidatorRule::Required);
+
+            for (err_key, mut default_err) in failures {
+                // ...translate the default message if the locale has a template for the rule
+                if let Some(template) = options.locale.template(rule) {
+                    default_err.message = render_template(template, &err_key, rule);
+                }
+
+                // ...and show the received value if enabled
+                if options.show_values && err_key == *key && !sensitive {
+                    default_err.message = append_value(&default_err.message, value);
+                }
+
+                if severity == Severity::Warning {
+                    let FieldError { code, message } = resolve_error(error, default_err, &err_key, rule);
+                    warnings.push(Warning { field: err_key, code, message });
+                    continue;
+                }
+
+                // Initialize field errors if it does not exist.
+                if !result_errs.contains_key(&err_key) {
+                    result_errs.insert(err_key.clone(), Vec::new());
+                }
+
+                if let Some(error_list) = result_errs.get(&err_key) {
+                    let errors = add_error(error, default_err, error_list, &err_key, rule);
+                    result_errs.insert(err_key, errors);
+                }
+
+                if options.fail_fast {
+                    return Err(ValidationError(result_errs));
+                }
+            }
+
+            if short_circuit {
+                break;
+            }
+        }
+    }
+
+    if !result_errs.is_empty() {
+        return Err(ValidationError(result_errs));
+    }
+
+    Ok(warnings)
+}
+
+/// appends ```value``` to ```message```, before its final period if any
+fn append_value(message: &str, value: &Value) -> String {
+    match message.strip_suffix('.') {
+        Some(message) => format!("{} (got {}).", message, value),
+        None => format!("{} (got {})", message, value),
+    }
+}
+
+/// adds an error to ```error_list```.
+/// 
+/// Checks if there's a user ```defined_err``` and if there's none, adds the ```default_err```. The code of
+/// ```default_err``` is kept either way. A ```defined_err``` may use ```{field}``` and the ```rule```'s
+/// ```{value}```, ```{min}``` and ```{max}``` placeholders.
+/// 
+/// Returns the new ```error_list```. 
+fn add_error(
+    defined_err: &ValidatorErrorType,
+    default_err: FieldError,
+    error_list: &[FieldError],
+    field: &str,
+    rule: &ValidatorRule,
+) -> Vec<FieldError> {
+    let mut errors = error_list.to_vec();
+    errors.push(resolve_error(defined_err, default_err, field, rule));
+
+    errors
+}
+
+/// replaces the message of ```default_err``` with the rendered ```defined_err```, if any
+fn resolve_error(defined_err: &ValidatorErrorType, default_err: FieldError, field: &str, rule: &ValidatorRule) -> FieldError {
+    let mut error = default_err;
+
+    if let Some(err) = defined_err {
+        error.message = render_template(err, field, rule);
+    }
+
+    error
+}
+
+#[cfg(test)]
+#[derive(Serialize)]
+struct DemoStruct {
+    name: &'static str,
+    city: &'static str,
+    age: u8,
+    bio: Option<String>,
+    allow: bool,
+    password: &'static str,
+    email: &'static str,
+}
+
+#[cfg(test)]
+#[derive(serde::Serialize)]
+struct RequestData{
+    username: &'static str,
+    password: &'static str
+} 
+
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_validator() {
+        use super::*;
+
+        let demo = DemoStruct {
+            name: "Olamide",
+            city: "Nigeria",
+            age: 36,
+            bio: None,
+            allow: true,
+            password: "WhatAPass@003",
+            email: "myemail@gmailcom"
+        };
+
+        // declare validation rules for any field you wish to validate
+        let name_rule = declare_rule!("name", ValidatorRule::Length(12));
+        let age_rule = declare_rule!("age", ValidatorRule::Size(18), "You're under-aged!");
+
+        let mut bio_rule = declare_rule!("bio", ValidatorRule::Required);
+        insert_rule!(bio_rule, ValidatorRule::MinLength(12), "Bio is too short!"); // We can add more validation rules to a single field
+
+        let allow_rule = declare_rule!("allow", ValidatorRule::Bool);
+        let pass_rule = declare_rule!("password", ValidatorRule::Password(8), "Password is incorrect");
+        let email_rule = declare_rule!("email", ValidatorRule::Email);
+
+        // create your validator with declarations
+        let val = freeval!(
+            &demo,
+            vec![name_rule, age_rule, bio_rule, allow_rule, pass_rule, email_rule]
+        );
+
+        let result = val.validate();
+        // if let Err(e) = &result {
+        //     println!("errors {:?}", e);
+        // }
+        
+        assert!(result.is_err())
+    }
+
+    #[test]
+    fn demo_test() {
+        use super::*;
+
+        let data = RequestData {
+            username: "Olamide",
+            password: "myWeakPass"
+        };
     
         // first, declare validation rules for each field you wish to validate with (optional) error message...
         let mut username_rule = declare_rule!("username", ValidatorRule::LengthRange((8, 12)), "username length is too short! Must be between 8 and 12");
@@ -246,10 +1712,1264 @@ mod tests {
         let vr = validator.validate();
     
         if let Err(err) = &vr {
-            // err is an HashMap of each field and their validation errors(if any).
-            println!("validation errors: {:?}", err);
+            // err holds a map of each field and their validation errors(if any).
+            println!("validation errors: {}", err);
         }
 
         assert!(vr.is_err());
     }
+
+    #[test]
+    fn test_depends_on() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct Invoice {
+            subtotal: i64,
+            tax: i64,
+            total: i64,
+        }
+
+        // total must be the sum of subtotal and tax
+        fn is_sum(value: &Value, deps: &[&Value]) -> bool {
+            let parts: Option<Vec<i64>> = deps.iter().map(|d| d.as_i64()).collect();
+            match (value.as_i64(), parts) {
+                (Some(total), Some(parts)) => total == parts.iter().sum::<i64>(),
+                _ => false,
+            }
+        }
+
+        let rule = || declare_rule!(
+            "total",
+            ValidatorRule::DependsOn { fields: &["subtotal", "tax"], check: is_sum },
+            "total must equal subtotal + tax"
+        );
+
+        let valid = Invoice { subtotal: 100, tax: 8, total: 108 };
+        assert!(freeval!(&valid, vec![rule()]).validate().is_ok());
+
+        let invalid = Invoice { subtotal: 100, tax: 8, total: 100 };
+        let result = freeval!(&invalid, vec![rule()]).validate();
+        assert_eq!(result.unwrap_err().messages()["total"], vec!["total must equal subtotal + tax"]);
+    }
+
+    #[test]
+    fn test_match_field() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct SignUp {
+            password: &'static str,
+            password_confirmation: &'static str,
+        }
+
+        let matching = SignUp { password: "WhatAPass@003", password_confirmation: "WhatAPass@003" };
+        let mismatching = SignUp { password: "WhatAPass@003", password_confirmation: "WhatAPass@004" };
+
+        let rule = || declare_rule!("password_confirmation", ValidatorRule::MatchField("password"));
+
+        assert!(freeval!(&matching, vec![rule()]).validate().is_ok());
+
+        let result = freeval!(&mismatching, vec![rule()]).validate();
+        assert_eq!(
+            result.unwrap_err().messages()["password_confirmation"],
+            vec!["'password_confirmation' field must match 'password'."]
+        );
+    }
+
+    #[test]
+    fn test_warn_unmatched() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct Profile {
+            #[serde(rename = "userName")]
+            user_name: &'static str,
+        }
+
+        let data = Profile { user_name: "ab" };
+
+        // the Rust field name doesn't match the serialized key, so the rule does nothing
+        let validator = freeval!(&data, vec![declare_rule!("user_name", ValidatorRule::MinLength(3))]).warn_unmatched(true);
+        let warnings = validator.validate_with_warnings().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "user_name");
+        assert_eq!(warnings[0].code, "unmatched_field");
+
+        // off by default
+        let validator = freeval!(&data, vec![declare_rule!("user_name", ValidatorRule::MinLength(3))]);
+        assert!(validator.validate_with_warnings().unwrap().is_empty());
+
+        // declaring against the serialized key works
+        let validator = freeval!(&data, vec![declare_rule!("userName", ValidatorRule::MinLength(3))]).warn_unmatched(true);
+        assert!(validator.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_strict() {
+        use super::*;
+
+        let data = RequestData { username: "prodbyola", password: "WhatAPass@003" };
+        let rules = || vec![declare_rule!("nmae", ValidatorRule::Required), declare_rule!("username", ValidatorRule::Required)];
+
+        // the typo goes unnoticed...
+        assert!(freeval!(&data, rules()).validate().is_ok());
+
+        // ...unless validating strictly
+        let errs = freeval!(&data, rules()).validate_strict().unwrap_err();
+        assert_eq!(errs.error_count(), 1);
+        assert_eq!(errs.0["nmae"][0].code, "unknown_field");
+        assert_eq!(errs.messages()["nmae"], vec!["'nmae' field is not in the data."]);
+
+        assert!(RuleSet::new(rules()).validate_strict(&data).is_err());
+    }
+
+    #[test]
+    fn test_required_short_circuit() {
+        use super::*;
+
+        let data = serde_json::json!({ "email": null, "username": null });
+        let errs = freeval!(&data, vec![
+            declare_rule!("email", ValidatorRule::Required),
+            declare_rule!("email", ValidatorRule::MinLength(5)),
+            // rules declared before required still run
+            declare_rule!("username", ValidatorRule::NonEmpty),
+            declare_rule!("username", ValidatorRule::Required),
+            declare_rule!("username", ValidatorRule::MaxLength(5)),
+        ]).validate().unwrap_err();
+
+        assert_eq!(errs.0["email"].len(), 1);
+        assert_eq!(errs.0["email"][0].code, "required");
+        assert_eq!(errs.0["username"].len(), 2);
+
+        // a present value runs every rule
+        let data = serde_json::json!({ "email": "abc" });
+        let rules = vec![declare_rule!("email", ValidatorRule::Required), declare_rule!("email", ValidatorRule::MinLength(5))];
+        assert_eq!(freeval!(&data, rules).validate().unwrap_err().0["email"][0].code, "too_short");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_required_email_short_circuit() {
+        use super::*;
+
+        let data = serde_json::json!({ "email": null });
+        let rules = vec![declare_rule!("email", ValidatorRule::Required), declare_rule!("email", ValidatorRule::Email)];
+        let errs = freeval!(&data, rules).validate().unwrap_err().messages();
+        assert_eq!(errs["email"], vec!["'email' field cannot be null."]);
+    }
+
+    #[test]
+    fn test_clone_declarations() {
+        use super::*;
+
+        // rejects reserved usernames
+        struct Blacklist;
+
+        impl Validator for Blacklist {
+            fn validate(&self, field: &str, value: &Value) -> InnerValidationResult {
+                let err = format!("'{}' field is reserved.", field);
+                InnerValidationResult(value != "root" && value != "admin", err)
+            }
+
+            fn code(&self) -> &'static str {
+                "blacklisted"
+            }
+        }
+
+        #[derive(Serialize)]
+        struct Admin {
+            username: &'static str,
+            level: u8,
+        }
+
+        // a template of declarations, cloned per request
+        let template = vec![
+            declare_rule!("username", ValidatorRule::MinLength(5)),
+            declare_rule!("username", ValidatorRule::Boxed(Arc::new(Blacklist))),
+        ];
+
+        let user = RequestData { username: "root", password: "WhatAPass@003" };
+        let admin = Admin { username: "prodbyola", level: 1 };
+
+        let errs = freeval!(&user, template.clone()).validate().unwrap_err();
+        assert_eq!(errs.0["username"].len(), 2);
+        assert!(freeval!(&admin, template.clone()).validate().is_ok());
+
+        let debug = format!("{:?}", template[0]);
+        assert!(debug.contains("MinLength(5)") && debug.contains("username"));
+        assert!(format!("{:?}", template[1]).contains("\"blacklisted\""));
+
+        let validator = freeval!(&admin, template);
+        assert!(validator.clone().validate().is_ok());
+    }
+
+    #[test]
+    fn test_first_per_field() {
+        use super::*;
+
+        let data = RequestData { username: "ab cd", password: "WhatAPass@003" };
+        let rules = vec![
+            declare_rule!("username", ValidatorRule::MinLength(8)),
+            declare_rule!("username", ValidatorRule::NoWhitespace),
+            declare_rule!("username", ValidatorRule::Uppercase),
+        ];
+
+        let errs = freeval!(&data, rules).validate().unwrap_err();
+        assert_eq!(errs.error_count(), 3);
+
+        let first = errs.clone().first_per_field();
+        assert_eq!(first.error_count(), 1);
+        assert_eq!(first.messages()["username"], vec![errs.0["username"][0].message.clone()]);
+    }
+
+    #[test]
+    fn test_into_response_body() {
+        use super::*;
+
+        let data = RequestData { username: "ab", password: "WhatAPass@003" };
+        let errs = freeval!(&data, vec![declare_rule!("username", ValidatorRule::MinLength(3))]).validate().unwrap_err();
+
+        assert_eq!(ValidationError::STATUS_CODE, 422);
+        assert_eq!(
+            errs.into_response_body(),
+            serde_json::json!({
+                "status": 422,
+                "errors": {
+                    "username": [{ "code": "too_short", "message": "'username' field must be minimum of 3 characters." }]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_default_message() {
+        use super::*;
+
+        assert_eq!(ValidatorRule::MinLength(3).default_message("username"), "'username' field must be minimum of 3 characters.");
+        assert_eq!(
+            ValidatorRule::Optional(Box::new(ValidatorRule::MaxWords(5))).default_message("bio"),
+            "'bio' field must have at most 5 words."
+        );
+        assert_eq!(ValidatorRule::ArrayLength((1, 3)).default_message("tags"), "'tags' field must have between 1 and 3 items.");
+
+        // matches what validation reports
+        let data = RequestData { username: "ab", password: "WhatAPass@003" };
+        let errs = freeval!(&data, vec![declare_rule!("username", ValidatorRule::MinLength(3))]).validate().unwrap_err();
+        assert_eq!(errs.messages()["username"], vec![ValidatorRule::MinLength(3).default_message("username")]);
+    }
+
+    #[test]
+    fn test_default_message_matches_validation() {
+        use super::*;
+
+        let map = Map::new();
+        let policy = PasswordPolicy { max_length: Some(64), require_special: false, ..PasswordPolicy::new(10) };
+        let cases = vec![
+            (ValidatorRule::Length(3), Value::from("ab")),
+            (ValidatorRule::MaxSize(3), Value::from(5)),
+            (ValidatorRule::MinSizeI64(3), Value::from(1)),
+            (ValidatorRule::MaxSizeU64(3), Value::from(5)),
+            (ValidatorRule::MultipleOf(0), Value::from(5)),
+            (ValidatorRule::StepFrom { base: 1, step: 5 }, Value::from(5)),
+            (ValidatorRule::Password(8), Value::from("weak")),
+            (ValidatorRule::PasswordPolicy(policy), Value::from("weak")),
+            (ValidatorRule::PasswordStrength(90), Value::from("weak")),
+            (ValidatorRule::Required, Value::Null),
+            (ValidatorRule::LengthRange((2, 4)), Value::from("abcdef")),
+            (ValidatorRule::SizeRange((2, 4)), Value::from(9)),
+            (ValidatorRule::LengthBounds((Bound::Excluded(2), Bound::Unbounded)), Value::from("ab")),
+            (ValidatorRule::FloatBounds((Bound::Unbounded, Bound::Included(1.5))), Value::from(2.0)),
+            (ValidatorRule::Contains(String::from("@")), Value::from("ab")),
+            (ValidatorRule::ContainsAll(&["a", "b"]), Value::from("c")),
+            (ValidatorRule::WordCountRange((2, 3)), Value::from("one")),
+            (ValidatorRule::PostalCode("US"), Value::from("abc")),
+            (ValidatorRule::Ean13, Value::from("123")),
+            (ValidatorRule::UuidVersion(4), Value::from("abc")),
+            (ValidatorRule::ArrayLength((1, 2)), Value::Array(Vec::new())),
+            (ValidatorRule::ArrayContains("rust"), Value::Array(Vec::new())),
+            (ValidatorRule::FileExtension(&["png", "jpg"]), Value::from("a.gif")),
+            (ValidatorRule::Enum(&["red", "blue"]), Value::from("green")),
+            (ValidatorRule::DependsOn { fields: &["a", "b"], check: |_, _| false }, Value::from(1)),
+            (ValidatorRule::MatchField("password"), Value::from("x")),
+            (ValidatorRule::RequiredWithout("phone"), Value::Null),
+            (ValidatorRule::Optional(Box::new(ValidatorRule::Even)), Value::from(3)),
+            (ValidatorRule::AnyOf(vec![ValidatorRule::Even, ValidatorRule::GreaterThan(10)]), Value::from(3)),
+            (ValidatorRule::Not(Box::new(ValidatorRule::Odd)), Value::from(3)),
+        ];
+
+        for (rule, value) in cases {
+            let InnerValidationResult(status, err) = evaluate("field", &rule, value, &map);
+            assert!(!status, "{:?}", rule);
+            assert_eq!(rule.default_message("field"), err, "{:?}", rule);
+        }
+
+        // validators aren't run, so user callbacks have no side effects
+        let panics = ValidatorRule::DependsOn { fields: &["a"], check: |_, _| panic!("check was run") };
+        assert_eq!(panics.default_message("field"), "'field' field is inconsistent with 'a'.");
+
+        let mime = ValidatorRule::SizeLimitByMime { mime_field: "mime", limits: &[("image/png", 1024)] };
+        assert_eq!(mime.default_message("size"), "'size' field must not exceed the size limit of the MIME type in 'mime'.");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_default_message_email() {
+        use super::*;
+
+        assert_eq!(ValidatorRule::Email.default_message("email"), "'email' field must be a valid email address");
+    }
+
+    #[test]
+    fn test_enum() {
+        use super::*;
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "lowercase")]
+        enum Role {
+            Admin,
+            #[allow(dead_code)]
+            Editor,
+        }
+
+        #[derive(Serialize)]
+        struct Account {
+            role: Role,
+        }
+
+        let rule = || declare_rule!("role", ValidatorRule::Enum(&["admin", "editor"]));
+        assert!(freeval!(&Account { role: Role::Admin }, vec![rule()]).validate().is_ok());
+
+        let data = serde_json::json!({ "role": "Admin" });
+        let errs = freeval!(&data, vec![rule()]).validate().unwrap_err();
+        assert_eq!(errs.0["role"][0].code, "unknown_variant");
+        assert_eq!(errs.messages()["role"], vec!["'role' field must be one of: admin, editor."]);
+    }
+
+    #[test]
+    fn test_different_field() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct ChangePassword {
+            old_password: &'static str,
+            new_password: &'static str,
+        }
+
+        let same = ChangePassword { old_password: "WhatAPass@003", new_password: "WhatAPass@003" };
+        let different = ChangePassword { old_password: "WhatAPass@003", new_password: "WhatAPass@004" };
+
+        let rule = || declare_rule!("new_password", ValidatorRule::DifferentField("old_password"));
+
+        assert!(freeval!(&same, vec![rule()]).validate().is_err());
+        assert!(freeval!(&different, vec![rule()]).validate().is_ok());
+    }
+
+    #[test]
+    fn test_required_with() {
+        use super::*;
+
+        let rule = || declare_rule!("cvv", ValidatorRule::RequiredWith("card_number"));
+
+        // triggered: card_number is set, so cvv is required
+        let missing = serde_json::json!({ "card_number": "4111111111111111" });
+        let errs = freeval!(&missing, vec![rule()]).validate().unwrap_err();
+        assert_eq!(errs.0["cvv"][0].code, "required");
+        assert_eq!(errs.messages()["cvv"], vec!["'cvv' field is required when 'card_number' is present."]);
+
+        let present = serde_json::json!({ "card_number": "4111111111111111", "cvv": "123" });
+        assert!(freeval!(&present, vec![rule()]).validate().is_ok());
+
+        // not triggered: card_number is missing or null
+        let empty = serde_json::json!({});
+        assert!(freeval!(&empty, vec![rule()]).validate().is_ok());
+
+        let null = serde_json::json!({ "card_number": null, "cvv": null });
+        assert!(freeval!(&null, vec![rule()]).validate().is_ok());
+    }
+
+    #[test]
+    fn test_required_without() {
+        use super::*;
+
+        // at least one of email or phone must be present
+        let rules = || {
+            vec![
+                declare_rule!("email", ValidatorRule::RequiredWithout("phone")),
+                declare_rule!("phone", ValidatorRule::RequiredWithout("email")),
+            ]
+        };
+
+        let neither = serde_json::json!({ "email": null });
+        let errs = freeval!(&neither, rules()).validate().unwrap_err();
+        assert_eq!(errs.messages()["email"], vec!["'email' field is required when 'phone' is not present."]);
+        assert_eq!(errs.0["phone"][0].code, "required");
+
+        let email = serde_json::json!({ "email": "user@example.com" });
+        assert!(freeval!(&email, rules()).validate().is_ok());
+
+        let phone = serde_json::json!({ "email": null, "phone": "+2348012345678" });
+        assert!(freeval!(&phone, rules()).validate().is_ok());
+
+        let both = serde_json::json!({ "email": "user@example.com", "phone": "+2348012345678" });
+        assert!(freeval!(&both, rules()).validate().is_ok());
+    }
+
+    #[test]
+    fn test_boxed_validator() {
+        use super::*;
+
+        // accepts "YYYY-MM-DD" dates that fall on a weekday
+        struct Weekday;
+
+        impl Validator for Weekday {
+            fn validate(&self, field: &str, value: &Value) -> InnerValidationResult {
+                let err = format!("'{}' field must be a weekday.", field);
+                let parts: Vec<i64> = value
+                    .as_str()
+                    .unwrap_or_default()
+                    .split('-')
+                    .filter_map(|p| p.parse().ok())
+                    .collect();
+
+                if parts.len() != 3 {
+                    return InnerValidationResult(false, err);
+                }
+
+                // Sakamoto's method, 0 = Sunday
+                let t = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+                let (d, m) = (parts[2], parts[1]);
+                let y = if m < 3 { parts[0] - 1 } else { parts[0] };
+                let day = (y + y / 4 - y / 100 + y / 400 + t[(m - 1) as usize] + d) % 7;
+
+                InnerValidationResult((1..=5).contains(&day), err)
+            }
+        }
+
+        #[derive(Serialize)]
+        struct Meeting {
+            date: &'static str,
+        }
+
+        let monday = Meeting { date: "2023-04-03" };
+        let saturday = Meeting { date: "2023-04-08" };
+
+        let rule = || declare_rule!("date", ValidatorRule::Boxed(Arc::new(Weekday)));
+
+        assert!(freeval!(&monday, vec![rule()]).validate().is_ok());
+
+        let result = freeval!(&saturday, vec![rule()]).validate();
+        assert_eq!(result.unwrap_err().messages()["date"], vec!["'date' field must be a weekday."]);
+    }
+
+    #[test]
+    fn test_validation_error() {
+        use super::*;
+
+        fn check(data: &RequestData) -> Result<(), Box<dyn Error>> {
+            let rule = declare_rule!("password", ValidatorRule::Password(8), "Password unacceptable!");
+            freeval!(data, vec![rule]).validate()?;
+            Ok(())
+        }
+
+        let weak = RequestData { username: "Olamide", password: "myWeakPass" };
+        let strong = RequestData { username: "Olamide", password: "WhatAPass@003" };
+
+        assert!(check(&strong).is_ok());
+
+        let err = check(&weak).unwrap_err();
+        assert_eq!(err.to_string(), "password: Password unacceptable!");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_error_order() {
+        use super::*;
+
+        let demo = DemoStruct {
+            name: "Olamide",
+            city: "Nigeria",
+            age: 12,
+            bio: None,
+            allow: false,
+            password: "weak",
+            email: "myemail@gmailcom"
+        };
+
+        let rules = || vec![
+            declare_rule!("password", ValidatorRule::Password(8)),
+            declare_rule!("email", ValidatorRule::Email),
+            declare_rule!("age", ValidatorRule::MinSize(18)),
+            declare_rule!("name", ValidatorRule::Length(12)),
+        ];
+
+        let first = freeval!(&demo, rules()).validate().unwrap_err();
+        let second = freeval!(&demo, rules()).validate().unwrap_err();
+
+        let fields: Vec<&String> = first.0.keys().collect();
+        assert_eq!(fields, vec!["age", "email", "name", "password"]);
+        assert_eq!(first.to_string(), second.to_string());
+    }
+
+    #[test]
+    fn test_nested_fields() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct Country {
+            code: &'static str,
+        }
+
+        #[derive(Serialize)]
+        struct Address {
+            city: &'static str,
+            country: Country,
+        }
+
+        #[derive(Serialize)]
+        struct Profile {
+            name: &'static str,
+            address: Option<Address>,
+        }
+
+        let rules = || vec![
+            declare_rule!("address.city", ValidatorRule::MinLength(2)),
+            declare_rule!("address.country.code", ValidatorRule::Length(2)),
+            declare_rule!("address.zip", ValidatorRule::Required), // unknown keys are skipped
+        ];
+
+        let valid = Profile {
+            name: "Olamide",
+            address: Some(Address { city: "Lagos", country: Country { code: "NG" } }),
+        };
+        assert!(freeval!(&valid, rules()).validate().is_ok());
+
+        let invalid = Profile {
+            name: "Olamide",
+            address: Some(Address { city: "L", country: Country { code: "NGA" } }),
+        };
+        let errs = freeval!(&invalid, rules()).validate().unwrap_err().messages();
+        assert_eq!(errs["address.city"], vec!["'address.city' field must be minimum of 2 characters."]);
+        assert_eq!(errs["address.country.code"], vec!["'address.country.code' field must be exactly 2 characters."]);
+
+        // a missing intermediate object is validated as null
+        let missing = Profile { name: "Olamide", address: None };
+        let errs = freeval!(&missing, vec![declare_rule!("address.city", ValidatorRule::Required)]).validate().unwrap_err().messages();
+        assert_eq!(errs["address.city"], vec!["'address.city' field cannot be null."]);
+    }
+
+    #[test]
+    fn test_each() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct Post {
+            tags: Vec<&'static str>,
+            title: &'static str,
+        }
+
+        let post = Post { tags: vec!["rust", "validation", "a-very-long-tag-indeed", "serde", "another-long-tag-here"], title: "FreeVal" };
+        let rules = vec![
+            declare_rule!("tags", ValidatorRule::Each(Box::new(ValidatorRule::MaxLength(20)))),
+            declare_rule!("title", ValidatorRule::Each(Box::new(ValidatorRule::Required))),
+        ];
+
+        let errs = freeval!(&post, rules).validate().unwrap_err().messages();
+        let fields: Vec<&String> = errs.keys().collect();
+
+        assert_eq!(fields, vec!["tags[2]", "tags[4]", "title"]);
+        assert_eq!(errs["tags[2]"], vec!["'tags[2]' field must be maximum of 20 characters."]);
+        assert_eq!(errs["title"], vec!["'title' field must be an array."]);
+
+        // items of the wrong type fail instead of panicking
+        let data = serde_json::json!({ "tags": [1, "rust"] });
+        let rules = vec![declare_rule!("tags", ValidatorRule::Each(Box::new(ValidatorRule::NonEmpty)))];
+        let errs = freeval!(&data, rules).validate().unwrap_err().messages();
+        assert_eq!(errs["tags[0]"], vec!["'tags[0]' field must not be empty."]);
+        assert!(!errs.contains_key("tags[1]"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_optional() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct Contact {
+            email: Option<String>,
+        }
+
+        let rule = || declare_rule!("email", ValidatorRule::Optional(Box::new(ValidatorRule::Email)));
+
+        let absent = Contact { email: None };
+        let valid = Contact { email: Some("myemail@gmail.com".to_string()) };
+        let invalid = Contact { email: Some("myemail@gmailcom".to_string()) };
+
+        assert!(freeval!(&absent, vec![rule()]).validate().is_ok());
+        assert!(freeval!(&valid, vec![rule()]).validate().is_ok());
+        assert!(freeval!(&invalid, vec![rule()]).validate().is_err());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_any_of() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct Contact {
+            contact: &'static str,
+        }
+
+        // contact must be an email or an 11 digit phone number
+        let rule = || declare_rule!(
+            "contact",
+            ValidatorRule::AnyOf(vec![ValidatorRule::Email, ValidatorRule::Length(11)])
+        );
+
+        let email = Contact { contact: "myemail@gmail.com" };
+        let phone = Contact { contact: "08012345678" };
+        let neither = Contact { contact: "Olamide" };
+
+        assert!(freeval!(&email, vec![rule()]).validate().is_ok());
+        assert!(freeval!(&phone, vec![rule()]).validate().is_ok());
+
+        let errs = freeval!(&neither, vec![rule()]).validate().unwrap_err().messages();
+        assert_eq!(
+            errs["contact"],
+            vec!["'contact' field must be a valid email address or 'contact' field must be exactly 11 characters."]
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_not() {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct Account {
+            username: &'static str,
+            bio: &'static str,
+        }
+
+        let rules = || vec![
+            declare_rule!("username", ValidatorRule::Not(Box::new(ValidatorRule::Email))),
+            declare_rule!("bio", ValidatorRule::Not(Box::new(ValidatorRule::Contains(String::from("http"))))),
+        ];
+
+        let valid = Account { username: "prodbyola", bio: "I love rust" };
+        assert!(freeval!(&valid, rules()).validate().is_ok());
+
+        let invalid = Account { username: "myemail@gmail.com", bio: "visit https://example.com" };
+        let errs = freeval!(&invalid, rules()).validate().unwrap_err().messages();
+
+        assert_eq!(errs["username"], vec!["'username' field must not satisfy: 'username' field must be a valid email address"]);
+        assert_eq!(errs["bio"].len(), 1);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_rule_set() {
+        use super::*;
+
+        let demo = |name, age, email| DemoStruct {
+            name,
+            city: "Nigeria",
+            age,
+            bio: None,
+            allow: true,
+            password: "WhatAPass@003",
+            email,
+        };
+
+        let items: Vec<DemoStruct> = ["Olamide", "Ola", "Olamide"]
+            .into_iter()
+            .zip([36, 12, 36])
+            .zip(["myemail@gmail.com", "myemail@gmail.com", "myemail@gmailcom"])
+            .map(|((name, age), email)| demo(name, age, email))
+            .collect();
+
+        let rules = RuleSet::new(vec![
+            declare_rule!("name", ValidatorRule::MinLength(5)),
+            declare_rule!("age", ValidatorRule::MinSize(18)),
+            declare_rule!("email", ValidatorRule::Email),
+        ]);
+
+        let results: Vec<bool> = items.iter().map(|item| rules.validate(item).is_ok()).collect();
+        assert_eq!(results, vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        use super::*;
+
+        let items = [
+            RequestData { username: "Ola", password: "WhatAPass@003" },
+            RequestData { username: "Olamide", password: "WhatAPass@003" },
+            RequestData { username: "Olamide", password: "pass" },
+        ];
+
+        let rules = RuleSet::new(vec![
+            declare_rule!("username", ValidatorRule::MinLength(5)),
+            declare_rule!("password", ValidatorRule::Password(8)),
+        ]);
+
+        let report = rules.validate_batch(&items);
+        let indexes: Vec<usize> = report.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indexes, vec![0, 2]);
+        assert!(report[0].1.field_errors("username").is_some());
+        assert!(report[1].1.field_errors("password").is_some());
+
+        assert!(rules.validate_batch(&items[1..2]).is_empty());
+    }
+
+    #[test]
+    fn test_validate_fast() {
+        use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // counts how many times it's evaluated
+        struct Counter(Arc<AtomicUsize>);
+
+        impl Validator for Counter {
+            fn validate(&self, _field: &str, _value: &Value) -> InnerValidationResult {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                InnerValidationResult(false, String::from("counted"))
+            }
+        }
+
+        let data = RequestData { username: "Olamide", password: "myWeakPass" };
+        let count = Arc::new(AtomicUsize::new(0));
+
+        let mut username_rule = declare_rule!("username", ValidatorRule::MinLength(8), "username is too short");
+        insert_rule!(username_rule, ValidatorRule::Boxed(Arc::new(Counter(count.clone()))));
+        let pass_rule = declare_rule!("password", ValidatorRule::Boxed(Arc::new(Counter(count.clone()))));
+
+        let validator = freeval!(&data, vec![username_rule, pass_rule]);
+
+        let result = validator.validate_fast();
+        assert_eq!(result, Err((String::from("username"), String::from("username is too short"))));
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+
+        // validate runs every rule
+        assert!(validator.validate().is_err());
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_validate_field() {
+        use super::*;
+
+        let demo = DemoStruct {
+            name: "Olamide",
+            city: "Nigeria",
+            age: 12,
+            bio: None,
+            allow: false,
+            password: "weak",
+            email: "myemail@gmailcom"
+        };
+
+        let validator = freeval!(&demo, vec![
+            declare_rule!("age", ValidatorRule::MinSize(18)),
+            declare_rule!("email", ValidatorRule::Email),
+            declare_rule!("password", ValidatorRule::Password(8)),
+        ]);
+
+        assert_eq!(validator.validate_field("email"), Err(vec![String::from("'email' field must be a valid email address")]));
+        assert!(validator.validate_field("name").is_ok());
+        assert_eq!(validator.validate().unwrap_err().0.len(), 3);
+    }
+
+    #[test]
+    fn test_error_json() {
+        use super::*;
+
+        let data = RequestData { username: "Olamide", password: "myWeakPass" };
+
+        let mut username_rule = declare_rule!("username", ValidatorRule::MinLength(8), "username is too short");
+        insert_rule!(username_rule, ValidatorRule::Contains(String::from("_")), "username must contain '_'");
+        let pass_rule = declare_rule!("password", ValidatorRule::Password(8), "Password unacceptable!");
+
+        let err = freeval!(&data, vec![username_rule, pass_rule]).validate().unwrap_err();
+        let expected = serde_json::json!({
+            "password": ["Password unacceptable!"],
+            "username": ["username is too short", "username must contain '_'"],
+        });
+
+        assert_eq!(err.to_json(), expected);
+        assert_eq!(serde_json::to_value(&err).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_error_codes() {
+        use super::*;
+
+        let data = RequestData { username: "Olamide", password: "myWeakPass" };
+        let rules = vec![
+            declare_rule!("username", ValidatorRule::MinLength(8)),
+            declare_rule!("password", ValidatorRule::Password(8), "Password unacceptable!"),
+        ];
+
+        let err = freeval!(&data, rules).validate().unwrap_err();
+
+        assert_eq!(
+            err.0["username"],
+            vec![FieldError { code: "too_short", message: String::from("'username' field must be minimum of 8 characters.") }]
+        );
+
+        // custom messages keep the rule's code
+        assert_eq!(err.0["password"][0].code, "weak_password");
+        assert_eq!(err.messages()["password"], vec!["Password unacceptable!"]);
+    }
+
+    #[test]
+    fn test_message_template() {
+        use super::*;
+
+        let demo = DemoStruct {
+            name: "Ola",
+            city: "Nigeria",
+            age: 12,
+            bio: None,
+            allow: true,
+            password: "WhatAPass@003",
+            email: "myemail@gmail.com"
+        };
+
+        let rules = vec![
+            declare_rule!("name", ValidatorRule::MinLength(5), "'{field}' must be at least {value} characters"),
+            declare_rule!("age", ValidatorRule::SizeRange((18, 65)), "{field} must be between {min} and {max}, {unknown}"),
+        ];
+
+        let errs = freeval!(&demo, rules).validate().unwrap_err().messages();
+        assert_eq!(errs["name"], vec!["'name' must be at least 5 characters"]);
+        assert_eq!(errs["age"], vec!["age must be between 18 and 65, {unknown}"]);
+    }
+
+    #[test]
+    fn test_locale() {
+        use super::*;
+
+        let demo = DemoStruct {
+            name: "Ola",
+            city: "Nigeria",
+            age: 36,
+            bio: None,
+            allow: true,
+            password: "WhatAPass@003",
+            email: "myemail@gmail.com"
+        };
+
+        let rules = || vec![
+            declare_rule!("bio", ValidatorRule::Required),
+            declare_rule!("name", ValidatorRule::MinLength(5)),
+            declare_rule!("city", ValidatorRule::Uppercase), // no translation
+        ];
+
+        let errs = freeval!(&demo, rules()).with_locale(Locale::French).validate().unwrap_err().messages();
+        assert_eq!(errs["bio"], vec!["Le champ 'bio' ne peut pas être nul."]);
+        assert_eq!(errs["name"], vec!["Le champ 'name' doit contenir au minimum 5 caractères."]);
+        assert_eq!(errs["city"], vec!["'city' field must be uppercase."]);
+
+        let errs = freeval!(&demo, rules()).validate().unwrap_err().messages();
+        assert_eq!(errs["bio"], vec!["'bio' field cannot be null."]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_builder() {
+        use super::*;
+
+        let demo = DemoStruct {
+            name: "Olamide",
+            city: "Nigeria",
+            age: 36,
+            bio: None,
+            allow: true,
+            password: "WhatAPass@003",
+            email: "myemail@gmailcom"
+        };
+
+        let validator = FreeVal::builder(&demo)
+            .rule("name", ValidatorRule::Length(12), None)
+            .rule("age", ValidatorRule::Size(18), Some("You're under-aged!"))
+            .rule("bio", ValidatorRule::Required, None)
+            .rule("bio", ValidatorRule::MinLength(12), Some("Bio is too short!"))
+            .rule("allow", ValidatorRule::Bool, None)
+            .rule("password", ValidatorRule::Password(8), Some("Password is incorrect"))
+            .rule("email", ValidatorRule::Email, None)
+            .build();
+
+        assert_eq!(validator.declarations.len(), 6);
+
+        let errs = validator.validate().unwrap_err().messages();
+        let fields: Vec<&String> = errs.keys().collect();
+        assert_eq!(fields, vec!["age", "bio", "email", "name"]);
+        assert_eq!(errs["bio"], vec!["'bio' field cannot be null."]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_merge_declarations() {
+        use super::*;
+
+        let data = RequestData { username: "Ola", password: "WhatAPass@003" };
+
+        let validator = freeval!(&data, vec![
+            declare_rule!("username", ValidatorRule::MinLength(5), "too short"),
+            declare_rule!("password", ValidatorRule::Password(8)),
+            declare_rule!("username", ValidatorRule::Contains(String::from("_")), "missing '_'"),
+        ]);
+
+        assert_eq!(validator.declarations.len(), 2);
+        assert_eq!(validator.validate().unwrap_err().messages()["username"], vec!["too short", "missing '_'"]);
+
+        let mut decl = declare_rule!("username", ValidatorRule::Required);
+        decl.merge(declare_rule!("username", ValidatorRule::Email));
+        assert_eq!(decl.rules.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot merge declarations of different fields")]
+    fn test_merge_mismatch() {
+        use super::*;
+
+        let mut decl = declare_rule!("username", ValidatorRule::Required);
+        decl.merge(declare_rule!("password", ValidatorRule::Required));
+    }
+
+    #[test]
+    fn test_runtime_contains() {
+        use super::*;
+
+        // e.g. loaded from config at runtime
+        let required_word = ["ru", "st"].concat();
+
+        let data = RequestData { username: "rustacean", password: "WhatAPass@003" };
+        let validator = freeval!(&data, vec![declare_rule!("username", ValidatorRule::Contains(required_word.clone()))]);
+        assert!(validator.validate().is_ok());
+
+        let data = RequestData { username: "gopher", password: "WhatAPass@003" };
+        let validator = freeval!(&data, vec![declare_rule!("username", ValidatorRule::Contains(required_word))]);
+        assert_eq!(
+            validator.validate().unwrap_err().messages()["username"],
+            vec!["'username' field must contain  'rust'. Please check again."]
+        );
+    }
+
+    #[test]
+    fn test_ignore_case_rules() {
+        use super::*;
+
+        let data = RequestData { username: "RustFan", password: "WhatAPass@003" };
+        let validator = freeval!(
+            &data,
+            vec![
+                declare_rule!("username", ValidatorRule::ContainsIgnoreCase("rust".to_string())),
+                declare_rule!("password", ValidatorRule::EqualsIgnoreCase("whatapass@003")),
+            ]
+        );
+        assert!(validator.validate().is_ok());
+
+        let data = RequestData { username: "GoFan", password: "other" };
+        let validator = freeval!(
+            &data,
+            vec![
+                declare_rule!("username", ValidatorRule::ContainsIgnoreCase("RUST".to_string())),
+                declare_rule!("password", ValidatorRule::EqualsIgnoreCase("whatapass@003")),
+            ]
+        );
+        let err = validator.validate().unwrap_err();
+        assert_eq!(err.0["username"][0].code, "missing_substring");
+        assert_eq!(err.0["password"][0].code, "not_equal");
+    }
+
+    #[test]
+    fn test_declare_rules() {
+        use super::*;
+
+        let data = serde_json::json!({ "name": "Adeola", "email": null, "age": null });
+        let declarations = declare_rules!(["name", "email", "age"], ValidatorRule::Required);
+        assert_eq!(declarations.len(), 3);
+
+        let err = freeval!(&data, declarations).validate().unwrap_err();
+        assert_eq!(
+            err.messages(),
+            BTreeMap::from([
+                ("age".to_string(), vec!["'age' field cannot be null.".to_string()]),
+                ("email".to_string(), vec!["'email' field cannot be null.".to_string()]),
+            ])
+        );
+
+        let declarations = declare_rules!(["email", "age"], ValidatorRule::Required, "required");
+        let err = freeval!(&data, declarations).validate().unwrap_err();
+        assert_eq!(err.messages()["age"], vec!["required"]);
+    }
+
+    #[test]
+    fn test_when() {
+        use super::*;
+
+        let rule = || {
+            declare_rule!(
+                "state",
+                ValidatorRule::When { field: "country", equals: "US", then: Box::new(ValidatorRule::Required) }
+            )
+        };
+
+        // condition met
+        let data = serde_json::json!({ "country": "US", "state": null });
+        let err = freeval!(&data, vec![rule()]).validate().unwrap_err();
+        assert_eq!(err.0["state"][0].code, "required");
+        assert_eq!(err.messages()["state"], vec!["'state' field cannot be null."]);
+
+        let data = serde_json::json!({ "country": "US", "state": "Texas" });
+        assert!(freeval!(&data, vec![rule()]).validate().is_ok());
+
+        // condition not met
+        let data = serde_json::json!({ "country": "NG", "state": null });
+        assert!(freeval!(&data, vec![rule()]).validate().is_ok());
+    }
+
+    #[test]
+    fn test_is_valid() {
+        use super::*;
+
+        let rules = || vec![declare_rule!("password", ValidatorRule::Password(8))];
+
+        let strong = RequestData { username: "Olamide", password: "WhatAPass@003" };
+        let weak = RequestData { username: "Olamide", password: "myWeakPass" };
+
+        assert!(freeval!(&strong, rules()).is_valid());
+        assert!(!freeval!(&weak, rules()).is_valid());
+
+        let rule_set = RuleSet::new(rules());
+        assert!(rule_set.is_valid(&strong));
+        assert!(!rule_set.is_valid(&weak));
+    }
+
+    #[test]
+    fn test_error_helpers() {
+        use super::*;
+
+        let demo = DemoStruct {
+            name: "Olamide",
+            city: "Nigeria",
+            age: 12,
+            bio: None,
+            allow: false,
+            password: "weak",
+            email: "myemail@gmailcom"
+        };
+
+        let mut password_rule = declare_rule!("password", ValidatorRule::MinLength(8), "too short");
+        insert_rule!(password_rule, ValidatorRule::Contains(String::from("@")), "missing '@'");
+        insert_rule!(password_rule, ValidatorRule::Uppercase, "not uppercase");
+
+        let err = freeval!(&demo, vec![
+            password_rule,
+            declare_rule!("age", ValidatorRule::MinSize(18), "too young"),
+        ]).validate().unwrap_err();
+
+        assert!(err.has_errors());
+        assert_eq!(err.error_count(), 4);
+        assert_eq!(err.field_errors("password"), Some(vec!["too short", "missing '@'", "not uppercase"]));
+        assert_eq!(err.field_errors("age"), Some(vec!["too young"]));
+        assert_eq!(err.field_errors("name"), None);
+        assert_eq!(err.first_error(), Some("too young"));
+
+        let empty = ValidationError(ValidationErrors::new());
+        assert!(!empty.has_errors());
+        assert_eq!(empty.error_count(), 0);
+        assert_eq!(empty.first_error(), None);
+    }
+
+    #[test]
+    fn test_array_length_rule() {
+        use super::*;
+
+        let rule = || declare_rule!("tags", ValidatorRule::ArrayLength((1, 5)));
+
+        let data = serde_json::json!({ "tags": ["rust", "serde"] });
+        assert!(freeval!(&data, vec![rule()]).validate().is_ok());
+
+        let data = serde_json::json!({ "tags": [] });
+        let err = freeval!(&data, vec![rule()]).validate().unwrap_err();
+        assert_eq!(err.0["tags"][0].code, "array_length_out_of_range");
+        assert_eq!(err.0["tags"][0].message, "'tags' field must have between 1 and 5 items.");
+
+        let data = serde_json::json!({ "tags": "rust" });
+        let err = freeval!(&data, vec![rule()]).validate().unwrap_err();
+        assert_eq!(err.0["tags"][0].code, "not_array");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_validate_value() {
+        use super::*;
+
+        let declarations = vec![
+            declare_rule!("email", ValidatorRule::Email),
+            declare_rule!("address.city", ValidatorRule::Required),
+        ];
+
+        let body = serde_json::json!({ "email": "olamide@example.com", "address": { "city": "Lagos" } });
+        assert!(validate_value(&body, &declarations).is_ok());
+
+        let body = serde_json::json!({ "email": "olamide", "address": { "city": null } });
+        let err = validate_value(&body, &declarations).unwrap_err();
+        assert_eq!(err.0["email"][0].code, "invalid_email");
+        assert_eq!(err.0["address.city"][0].code, "required");
+
+        assert!(validate_value(&serde_json::json!([1, 2]), &declarations).is_ok());
+
+        // wrong-typed fields of an untyped body fail instead of panicking
+        let declarations = vec![
+            declare_rule!("email", ValidatorRule::Email),
+            declare_rule!("username", ValidatorRule::NoWhitespace),
+            declare_rule!("name", ValidatorRule::Trimmed),
+            declare_rule!("host", ValidatorRule::Hostname),
+            declare_rule!("iban", ValidatorRule::Iban),
+            declare_rule!("schedule", ValidatorRule::Cron),
+            declare_rule!("dob", ValidatorRule::Date),
+            declare_rule!("policy", ValidatorRule::ContainsAll(&["privacy"])),
+            declare_rule!("bio", ValidatorRule::LengthBounds((Bound::Included(1), Bound::Unbounded))),
+            declare_rule!("title", ValidatorRule::MinLength(3)),
+            declare_rule!("nickname", ValidatorRule::LengthRange((2, 10))),
+        ];
+        let body = serde_json::json!({
+            "email": 42, "username": 5, "name": true, "host": [1], "iban": 1.5, "schedule": 5,
+            "dob": 20230403, "policy": { "privacy": true }, "bio": 7, "title": false,
+            "nickname": 3,
+        });
+        let err = validate_value(&body, &declarations).unwrap_err();
+        assert_eq!(err.error_count(), declarations.len());
+    }
+
+    #[test]
+    fn test_warnings() {
+        use super::*;
+
+        let rules = || {
+            let mut username_rule = declare_rule!("username", ValidatorRule::MinLength(3));
+            insert_warning!(username_rule, ValidatorRule::MinLength(8), "usernames will soon need 8 characters");
+            vec![username_rule, declare_warning!("password", ValidatorRule::Password(16))]
+        };
+
+        let data = RequestData { username: "Olamide", password: "WhatAPass@003" };
+        let validator = freeval!(&data, rules());
+        assert!(validator.validate().is_ok());
+
+        let warnings = validator.validate_with_warnings().unwrap();
+        assert_eq!(
+            warnings,
+            vec![
+                Warning {
+                    field: "username".to_string(),
+                    code: "too_short",
+                    message: "usernames will soon need 8 characters".to_string(),
+                },
+                Warning {
+                    field: "password".to_string(),
+                    code: "weak_password",
+                    message: password("password", Value::from("WhatAPass@003"), 16).1,
+                },
+            ]
+        );
+
+        // errors still fail validation, and warnings aren't mixed into them
+        let data = RequestData { username: "Ol", password: "WhatAPass@003" };
+        let err = RuleSet::new(rules()).validate_with_warnings(&data).unwrap_err();
+        assert_eq!(err.messages()["username"], vec!["'username' field must be minimum of 3 characters."]);
+        assert!(!err.0.contains_key("password"));
+    }
+
+    #[test]
+    fn test_bounds_rules() {
+        use super::*;
+
+        let data = serde_json::json!({ "age": 0, "discount": 1.0 });
+        let errs = freeval!(&data, vec![
+            declare_rule!("age", ValidatorRule::SizeBounds((Bound::Excluded(0), Bound::Unbounded)), "{field} must be above {min}"),
+            declare_rule!("discount", ValidatorRule::FloatBounds((Bound::Included(0.0), Bound::Excluded(1.0)))),
+        ]).validate().unwrap_err();
+
+        assert_eq!(errs.0["age"][0].code, "size_out_of_range");
+        assert_eq!(errs.messages()["age"], vec!["age must be above 0"]);
+        assert_eq!(errs.messages()["discount"], vec!["'discount' field must be at least 0 and less than 1."]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_validate_async() {
+        use super::*;
+
+        // stands in for a database lookup
+        let available = || ValidatorRule::from_async(|value| async move { value != "admin" });
+
+        let taken = RequestData { username: "admin", password: "WhatAPass@003" };
+        let free = RequestData { username: "prodbyola", password: "WhatAPass@003" };
+
+        let rules = || vec![declare_rule!("username", available(), "{field} is already taken")];
+
+        // sync validation can't run async rules, so it fails them whatever the value
+        assert!(!freeval!(&free, rules()).is_valid());
+        let errs = freeval!(&free, vec![declare_rule!("username", available())]).validate().unwrap_err();
+        assert_eq!(errs.messages()["username"], vec!["'username' field requires async validation."]);
+
+        let errs = freeval!(&taken, rules()).validate_async().await.unwrap_err();
+        assert_eq!(errs.0["username"][0].code, "custom");
+        assert_eq!(errs.messages()["username"], vec!["username is already taken"]);
+
+        assert!(freeval!(&free, rules()).validate_async().await.is_ok());
+
+        // sync failures are reported alongside async ones
+        let rules = vec![
+            declare_rule!("username", available()),
+            declare_rule!("password", ValidatorRule::MinLength(20)),
+        ];
+        let errs = freeval!(&taken, rules).validate_async().await.unwrap_err();
+        assert_eq!(errs.error_count(), 2);
+
+        // nested async rules are awaited too
+        let optional = || vec![declare_rule!("username", ValidatorRule::Optional(Box::new(available())))];
+        assert!(freeval!(&serde_json::json!({ "username": null }), optional()).validate_async().await.is_ok());
+        assert!(freeval!(&free, optional()).validate_async().await.is_ok());
+        let errs = freeval!(&taken, optional()).validate_async().await.unwrap_err();
+        assert_eq!(errs.messages()["username"], vec!["'username' field is invalid."]);
+
+        let each = vec![declare_rule!("usernames", ValidatorRule::Each(Box::new(available())))];
+        let errs = freeval!(&serde_json::json!({ "usernames": ["prodbyola", "admin"] }), each).validate_async().await.unwrap_err();
+        assert_eq!(errs.messages().keys().collect::<Vec<_>>(), vec!["usernames[1]"]);
+
+        // a failed async warning doesn't fail validation
+        assert!(freeval!(&taken, vec![declare_warning!("username", available())]).validate_async().await.is_ok());
+    }
+
+    #[test]
+    fn test_show_values() {
+        use super::*;
+
+        let data = serde_json::json!({ "age": 12, "password": "hunter2" });
+        let rules = || {
+            // MinLength isn't a password rule, but it checks the same secret value
+            let mut password_rule = declare_rule!("password", ValidatorRule::MinLength(8));
+            insert_rule!(password_rule, ValidatorRule::Password(8));
+            vec![declare_rule!("age", ValidatorRule::MinSize(18)), password_rule]
+        };
+
+        let errs = freeval!(&data, rules()).show_values(true).validate().unwrap_err().messages();
+        assert_eq!(errs["age"], vec!["'age' field must be minimum of 18 (got 12)."]);
+        assert_eq!(errs["password"].len(), 2);
+        assert!(errs["password"].iter().all(|m| !m.contains("hunter2") && !m.contains("got")));
+
+        // off by default
+        let errs = freeval!(&data, rules()).validate().unwrap_err().messages();
+        assert!(!errs["age"][0].contains("got"));
+
+        // user defined messages are kept as is
+        let rules = RuleSet::new(vec![declare_rule!("age", ValidatorRule::MinSize(18), "too young")]).show_values(true);
+        assert_eq!(rules.validate(&data).unwrap_err().messages()["age"], vec!["too young"]);
+    }
 }
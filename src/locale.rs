@@ -0,0 +1,47 @@
+use crate::ValidatorRule;
+
+/// Language of default error messages.
+///
+/// Translated messages are templates that may use ```{field}``` and the rule's ```{value}```, ```{min}``` and
+/// ```{max}``` placeholders. Rules without a translation fall back to the English default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    English,
+    French,
+}
+
+impl Locale {
+    /// Returns the message template of ```rule``` in this locale, or ```None``` to use the English default.
+    pub fn template(&self, rule: &ValidatorRule) -> Option<&'static str> {
+        match self {
+            Locale::English => None,
+            Locale::French => french(rule),
+        }
+    }
+}
+
+fn french(rule: &ValidatorRule) -> Option<&'static str> {
+    let template = match rule {
+        ValidatorRule::Required => "Le champ '{field}' ne peut pas être nul.",
+        #[cfg(feature = "regex")]
+        ValidatorRule::Email => "Le champ '{field}' doit être une adresse e-mail valide.",
+        ValidatorRule::Length(_) => "Le champ '{field}' doit contenir exactement {value} caractères.",
+        ValidatorRule::MaxLength(_) => "Le champ '{field}' doit contenir au maximum {value} caractères.",
+        ValidatorRule::MinLength(_) => "Le champ '{field}' doit contenir au minimum {value} caractères.",
+        ValidatorRule::Size(_) => "Le champ '{field}' doit être exactement {value}.",
+        ValidatorRule::MaxSize(_) | ValidatorRule::MaxSizeU64(_) => "Le champ '{field}' doit être au maximum {value}.",
+        ValidatorRule::MinSize(_) | ValidatorRule::MinSizeI64(_) => "Le champ '{field}' doit être au minimum {value}.",
+        ValidatorRule::LengthRange(_) => "La longueur de '{field}' doit être comprise entre {min} et {max}.",
+        ValidatorRule::SizeRange(_) => "La taille de '{field}' doit être comprise entre {min} et {max}.",
+        ValidatorRule::Password(_) => "Le champ '{field}' doit contenir au moins une majuscule, une minuscule, un chiffre et un caractère spécial et comporter au moins {value} caractères.",
+        ValidatorRule::Contains(_) | ValidatorRule::ContainsIgnoreCase(_) => "Le champ '{field}' doit contenir '{value}'.",
+        ValidatorRule::MatchField(_) => "Le champ '{field}' doit correspondre à '{value}'.",
+        ValidatorRule::Each(inner) | ValidatorRule::Optional(inner) | ValidatorRule::When { then: inner, .. } => {
+            return french(inner)
+        }
+        _ => return None,
+    };
+
+    Some(template)
+}
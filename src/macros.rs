@@ -15,6 +15,29 @@ macro_rules! declare_rule {
     }
 }
 
+/// Declares the same rule (and optional error message) for each of the given fields, returning a
+/// ```Vec<RuleDeclaration>```. The rule expression is evaluated once per field.
+#[macro_export]
+macro_rules! declare_rules {
+    ([$($field:expr),* $(,)?], $rule:expr) => {
+        vec![$(RuleDeclaration::new($field, $rule, None)),*]
+    };
+    ([$($field:expr),* $(,)?], $rule:expr, $err:expr) => {
+        vec![$(RuleDeclaration::new($field, $rule, Option::from($err))),*]
+    };
+}
+
+/// Like ```declare_rule!```, but the rule only reports a ```Warning``` when it fails.
+#[macro_export]
+macro_rules! declare_warning {
+    ($field:expr, $rule:expr) => {
+        RuleDeclaration::warning($field, $rule, None)
+    };
+    ($field:expr, $rule:expr, $err:expr) => {
+        RuleDeclaration::warning($field, $rule, Option::from($err))
+    };
+}
+
 #[macro_export]
 macro_rules! insert_rule {
     ($decl:expr, $rule:expr) => {
@@ -23,4 +46,15 @@ macro_rules! insert_rule {
     ($decl:expr, $rule:expr, $err:expr) => {
         $decl.insert($rule, Option::from($err))
     };
-}
\ No newline at end of file
+}
+
+/// Like ```insert_rule!```, but the rule only reports a ```Warning``` when it fails.
+#[macro_export]
+macro_rules! insert_warning {
+    ($decl:expr, $rule:expr) => {
+        $decl.insert_warning($rule, None)
+    };
+    ($decl:expr, $rule:expr, $err:expr) => {
+        $decl.insert_warning($rule, Option::from($err))
+    };
+}
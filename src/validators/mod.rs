@@ -1,16 +1,44 @@
-use std::fmt::{Display, Debug};
+use std::fmt::{self, Display, Debug};
+use std::ops::Bound;
+use std::str::FromStr;
+#[cfg(feature = "regex")]
+use std::collections::HashMap;
+#[cfg(feature = "regex")]
+use std::sync::{Mutex, OnceLock};
 
+#[cfg(feature = "regex")]
 use regex::Regex;
 use serde::de::DeserializeOwned;
-use serde_json::Value;
+use serde_json::{Map, Value};
+
+mod codes;
+
 pub enum LengthType {
     Exact,
     Max,
     Min,
 }
 
+/// Result of a single rule: whether it passed and the default error message.
 pub struct InnerValidationResult(pub bool, pub String);
 
+/// Implement to create custom (and stateful) validation rules for use with ```ValidatorRule::Boxed```.
+pub trait Validator {
+    /// validates ```value``` of ```field```
+    fn validate(&self, field: &str, value: &Value) -> InnerValidationResult;
+
+    /// code reported when validation fails
+    fn code(&self) -> &'static str {
+        "custom"
+    }
+}
+
+impl fmt::Debug for dyn Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Validator").field("code", &self.code()).finish()
+    }
+}
+
 impl LengthType {
     pub fn to_string(&self) -> &str {
         match self {
@@ -35,23 +63,56 @@ impl RangeType {
     }
 }
 
+/// resolves a (dotted) field ```path``` such as ```"address.city"``` in ```map```.
+/// 
+/// Returns ```None``` if a key on the path doesn't exist and ```null``` if an intermediate value is ```null```.
+pub fn lookup<'a>(map: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    if let Some(value) = map.get(path) {
+        return Some(value);
+    }
+
+    let mut keys = path.split('.');
+    let mut current = map.get(keys.next()?)?;
+
+    for key in keys {
+        match current {
+            Value::Object(obj) => current = obj.get(key)?,
+            Value::Null => return Some(current),
+            _ => return None,
+        }
+    }
+
+    Some(current)
+}
+
 /// checks the type of length to be validated
 fn check_len<T: PartialEq + PartialOrd>(rule: &T, vlen: &T, length_type: LengthType) -> bool {
-    let cond;
-
     match length_type {
-        LengthType::Max => cond = rule >= vlen,
-        LengthType::Min => cond = rule <= vlen,
-        LengthType::Exact => cond = rule == vlen
+        LengthType::Max => rule >= vlen,
+        LengthType::Min => rule <= vlen,
+        LengthType::Exact => rule == vlen
+    }
+}
+
+/// reads a number, or a numeric string such as ```"42"``` (e.g. a form field), returning ```None``` for other values
+fn extract_number<T: DeserializeOwned + FromStr>(value: Value) -> Option<T> {
+    match value {
+        Value::String(v) => v.trim().parse().ok(),
+        v => serde_json::from_value(v).ok(),
     }
+}
 
-    return cond;
+/// renders a value as text: strings as they are and other values as their JSON representation
+fn as_text(value: &Value) -> String {
+    match value {
+        Value::String(v) => v.clone(),
+        v => v.to_string(),
+    }
 }
 
-/// deserializes a value
-fn extract_value<T: DeserializeOwned + 'static>(value: Value) -> T {
-    let d: T = serde_json::from_value(value).expect("failed to extract result");
-    d
+/// error message of a string whose length doesn't satisfy ```rule```
+pub fn length_message(field: &str, rule: &usize, length_type: &LengthType) -> String {
+    format!("'{}' field must be {} {} characters.", field, length_type.to_string(), rule)
 }
 
 /// Validates length of strings or any type has ```len``` method. This is most suitable for strings at the moment.
@@ -61,18 +122,12 @@ pub fn length(
     value: Value,
     length_type: LengthType,
 ) -> InnerValidationResult {
-    let err = format!(
-        "'{}' field must be {} {} characters.",
-        field,
-        length_type.to_string(),
-        &rule
-    );
-
-    if value.is_null() {
-        return InnerValidationResult(false, err);
-    }
+    let err = length_message(field, rule, &length_type);
 
-    let v: String = extract_value(value);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
 
     let vlen = &v.len(); // length of value
     let cond = check_len(rule, vlen, length_type);
@@ -80,28 +135,138 @@ pub fn length(
     InnerValidationResult(cond, err)
 }
 
-/// Validates size of an integer
+/// error message of a number whose size doesn't satisfy ```rule```
+pub fn size_message<T: Display>(field: &str, rule: &T, length_type: &LengthType) -> String {
+    format!("'{}' field must be {} {}.", field, length_type.to_string(), rule)
+}
+
+/// Validates size of an integer or a numeric string such as ```"42"```. Other values fail.
 pub fn size(
     field: &str,
     rule: &isize,
     value: Value,
     length_type: LengthType,
 ) -> InnerValidationResult {
-    let err = format!(
-        "'{}' field must be {} {}.",
-        field,
-        length_type.to_string(),
-        &rule
-    );
+    let err = size_message(field, rule, &length_type);
+    // numeric strings are parsed, while other values fail
+    let cond = match extract_number::<isize>(value) {
+        Some(v) => check_len(rule, &v, length_type),
+        None => false,
+    };
+
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is at least ```rule```. Unlike [```size```] the bound is 64-bit on
+/// every target. Values are compared as ```i128```, so any JSON integer is checked, from ```i64::MIN``` to
+/// ```u64::MAX```. Other values fail.
+pub fn min_size_i64(field: &str, rule: &i64, value: Value) -> InnerValidationResult {
+    let err = size_message(field, rule, &LengthType::Min);
+    let cond = extract_number::<i128>(value).is_some_and(|v| v >= i128::from(*rule));
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is at most ```rule```. Unlike [```size```] the bound is 64-bit on
+/// every target. Values are compared as ```i128```, so any JSON integer is checked, from ```i64::MIN``` to
+/// ```u64::MAX```. Other values fail.
+pub fn max_size_u64(field: &str, rule: &u64, value: Value) -> InnerValidationResult {
+    let err = size_message(field, rule, &LengthType::Max);
+    let cond = extract_number::<i128>(value).is_some_and(|v| v <= i128::from(*rule));
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is strictly greater than ```rule```. Other values fail.
+pub fn greater_than(field: &str, rule: &isize, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be greater than {}.", field, rule);
+    let cond = extract_number::<isize>(value).is_some_and(|v| v > *rule);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is strictly less than ```rule```. Other values fail.
+pub fn less_than(field: &str, rule: &isize, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be less than {}.", field, rule);
+    let cond = extract_number::<isize>(value).is_some_and(|v| v < *rule);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is even. Other values fail.
+pub fn even(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be an even number.", field);
+    let cond = extract_number::<isize>(value).is_some_and(|v| v % 2 == 0);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is odd. Other values fail.
+pub fn odd(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be an odd number.", field);
+    let cond = extract_number::<isize>(value).is_some_and(|v| v % 2 != 0);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is a multiple of ```rule```. Other values and a zero ```rule```
+/// always fail.
+pub fn multiple_of(field: &str, rule: &isize, value: Value) -> InnerValidationResult {
+    if *rule == 0 {
+        let err = format!("'{}' field cannot be validated as a multiple of 0.", field);
+        return InnerValidationResult(false, err);
+    }
+
+    let err = format!("'{}' field must be a multiple of {}.", field, rule);
+    let cond = extract_number::<isize>(value).is_some_and(|v| v.wrapping_rem(*rule) == 0);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that an integer (or numeric string) is ```base``` plus a whole number of ```step```s. Other values and
+/// a zero ```step``` always fail.
+pub fn step_from(field: &str, base: isize, step: isize, value: Value) -> InnerValidationResult {
+    if step == 0 {
+        let err = format!("'{}' field cannot be validated with a step of 0.", field);
+        return InnerValidationResult(false, err);
+    }
+
+    let err = format!("'{}' field must be {} plus a multiple of {}.", field, base, step);
+    let cond = extract_number::<isize>(value)
+        .and_then(|v| v.checked_sub(base))
+        .and_then(|diff| diff.checked_rem(step))
+        == Some(0);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that value equals ```rule```. Non-string values are compared by their JSON representation, so
+/// ```42``` equals ```"42"```. Null always fails.
+pub fn equals(field: &str, rule: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be equal to '{}'.", field, rule);
     if value.is_null() {
         return InnerValidationResult(false, err);
     }
 
-    let v: isize = extract_value(value);
+    InnerValidationResult(as_text(&value) == rule, err)
+}
 
-    let vlen = &v; // length of value
-    let cond = check_len(rule, vlen, length_type);
+/// Like [```equals```], but lowercases both sides before comparing. Null always fails.
+pub fn equals_ignore_case(field: &str, rule: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be equal to '{}' (case-insensitive).", field, rule);
+    if value.is_null() {
+        return InnerValidationResult(false, err);
+    }
+
+    InnerValidationResult(as_text(&value).to_lowercase() == rule.to_lowercase(), err)
+}
+
+/// Validates that value doesn't equal ```rule```. Values are compared like [```equals```]. Null always fails.
+pub fn not_equals(field: &str, rule: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must not be equal to '{}'.", field, rule);
+    if value.is_null() {
+        return InnerValidationResult(false, err);
+    }
+
+    InnerValidationResult(as_text(&value) != rule, err)
+}
 
+/// Validates that value is a string equal to one of ```variants```, e.g. the serialized names of an enum's variants.
+pub fn enum_variant(field: &str, variants: &[&str], value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be one of: {}.", field, variants.join(", "));
+    let cond = value.as_str().is_some_and(|v| variants.contains(&v));
     InnerValidationResult(cond, err)
 }
 
@@ -111,21 +276,99 @@ pub fn required(field: &str, value: Value) -> InnerValidationResult {
     InnerValidationResult(!value.is_null(), err)
 }
 
-/// checks if a boolean condition is satified
+/// Validates that value is a boolean, whether ```true``` or ```false```. Use [```must_be_true```] to require ```true```.
 pub fn check_bool(field: &str, value: Value) -> InnerValidationResult {
-    let v: bool = extract_value(value);
-    let err = format!("'{}' field's condition must be satified.", field);
-    InnerValidationResult(v, err)
+    let err = format!("'{}' field must be a boolean.", field);
+    InnerValidationResult(value.is_boolean(), err)
+}
+
+/// Validates that value is ```true```, e.g. accepted terms. Non-boolean values fail.
+pub fn must_be_true(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be true.", field);
+    InnerValidationResult(value == Value::Bool(true), err)
+}
+
+/// Validates that value is ```false```. Non-boolean values fail.
+pub fn must_be_false(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be false.", field);
+    InnerValidationResult(value == Value::Bool(false), err)
+}
+
+/// Requirements of a password validated by ```ValidatorRule::PasswordPolicy```.
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+    /// minimum number of characters
+    pub min_length: usize,
+    /// maximum number of characters, guarding slow password hashing against absurdly long inputs
+    pub max_length: Option<usize>,
+    /// requires at least one uppercase letter
+    pub require_upper: bool,
+    /// requires at least one lowercase letter
+    pub require_lower: bool,
+    /// requires at least one digit
+    pub require_digit: bool,
+    /// requires at least one special (non alphanumeric) character
+    pub require_special: bool,
+    /// allows whitespace, e.g. for passphrases
+    pub allow_whitespace: bool,
+}
+
+impl PasswordPolicy {
+    /// creates a policy requiring every character class and no whitespace
+    pub fn new(min_length: usize) -> PasswordPolicy {
+        PasswordPolicy {
+            min_length,
+            max_length: None,
+            require_upper: true,
+            require_lower: true,
+            require_digit: true,
+            require_special: true,
+            allow_whitespace: false,
+        }
+    }
 }
 
 /// validate password
 pub fn password(field: &str, value: Value, len: usize) -> InnerValidationResult {
-    let err = format!("'{}' field must contain at least one uppercase letter, one lowercase letter, one digit and one special character and must be at least {} chars long.", field, &len);
-    if value.is_null() {
-        return InnerValidationResult(false, err);
+    password_policy(field, value, &PasswordPolicy::new(len))
+}
+
+/// error message of a password that doesn't satisfy ```policy```
+pub fn password_policy_message(field: &str, policy: &PasswordPolicy) -> String {
+    let mut classes = Vec::new();
+    if policy.require_upper {
+        classes.push("one uppercase letter");
+    }
+    if policy.require_lower {
+        classes.push("one lowercase letter");
+    }
+    if policy.require_digit {
+        classes.push("one digit");
     }
+    if policy.require_special {
+        classes.push("one special character");
+    }
+
+    let bounds = match policy.max_length {
+        Some(max) => format!("between {} and {}", policy.min_length, max),
+        None => format!("at least {}", policy.min_length),
+    };
+
+    match classes.split_last() {
+        Some((last, [])) => format!("'{}' field must contain at least {} and must be {} chars long.", field, last, bounds),
+        Some((last, rest)) => format!("'{}' field must contain at least {} and {} and must be {} chars long.", field, rest.join(", "), last, bounds),
+        None => format!("'{}' field must be {} chars long.", field, bounds),
+    }
+}
+
+/// validate password against a ```policy```
+pub fn password_policy(field: &str, value: Value, policy: &PasswordPolicy) -> InnerValidationResult {
+    let err = password_policy_message(field, policy);
 
-    let v: String = extract_value(value);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
 
     let mut has_whitespace = false;
     let mut has_upper = false;
@@ -137,33 +380,100 @@ pub fn password(field: &str, value: Value, len: usize) -> InnerValidationResult
         has_whitespace |= c.is_whitespace();
         has_lower |= c.is_lowercase();
         has_upper |= c.is_uppercase();
-        has_digit |= c.is_digit(10);
-        has_special_char |= !c.is_ascii_alphanumeric()
+        has_digit |= c.is_ascii_digit();
+        has_special_char |= !c.is_ascii_alphanumeric() && !c.is_whitespace()
     }
 
-    let cond = !has_whitespace
-        && has_upper
-        && has_lower
-        && has_digit
-        && has_special_char
-        && v.len() >= len;
+    let cond = (policy.allow_whitespace || !has_whitespace)
+        && (!policy.require_upper || has_upper)
+        && (!policy.require_lower || has_lower)
+        && (!policy.require_digit || has_digit)
+        && (!policy.require_special || has_special_char)
+        && v.len() >= policy.min_length
+        && policy.max_length.is_none_or(|max| v.len() <= max);
+    InnerValidationResult(cond, err)
+}
+
+/// Estimates the strength of a password from 0 (weakest) to 100, e.g. to display a strength meter.
+/// 
+/// Each character adds to the score up to 16 characters and each character class used (lowercase, uppercase,
+/// digit, special) adds a bonus, while characters repeating the previous one are penalized.
+pub fn password_strength(value: &str) -> u8 {
+    let chars: Vec<char> = value.chars().collect();
+
+    let length = chars.len().min(16) * 4;
+
+    let classes = [
+        chars.iter().any(|c| c.is_lowercase()),
+        chars.iter().any(|c| c.is_uppercase()),
+        chars.iter().any(|c| c.is_ascii_digit()),
+        chars.iter().any(|c| !c.is_alphanumeric()),
+    ];
+    let diversity = classes.iter().filter(|&&used| used).count() * 9;
+
+    let repeats = chars.windows(2).filter(|pair| pair[0] == pair[1]).count() * 5;
+
+    (length + diversity).saturating_sub(repeats).min(100) as u8
+}
+
+/// Validates that the [```password_strength```] of a password is at least ```min```
+pub fn min_password_strength(field: &str, value: Value, min: u8) -> InnerValidationResult {
+    let err = format!("'{}' field is too weak. Use a longer password mixing letters, digits and symbols.", field);
+    let cond = value.as_str().is_some_and(|v| password_strength(v) >= min);
     InnerValidationResult(cond, err)
 }
 
+#[cfg(feature = "regex")]
+static EMAIL_REGEX: OnceLock<Regex> = OnceLock::new();
+
 /// Validates email address
+#[cfg(feature = "regex")]
 pub fn email(field: &str, value: Value) -> InnerValidationResult {
     let err = format!("'{}' field must be a valid email address", field);
-    if value.is_null() {
-        return InnerValidationResult(false, err);
-    }
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let re = EMAIL_REGEX.get_or_init(|| {
+        Regex::new(r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}$").unwrap()
+    });
+    InnerValidationResult(re.is_match(v), err)
+}
+
+/// compiled patterns of ```ValidatorRule::MatchesAny```, or ```None``` for invalid patterns
+#[cfg(feature = "regex")]
+static PATTERN_CACHE: OnceLock<Mutex<HashMap<&'static str, Option<Regex>>>> = OnceLock::new();
+
+/// Validates that a string matches at least one of ```patterns```. Each pattern is compiled once and cached for
+/// later validations. Invalid patterns never match and non-string values fail.
+#[cfg(feature = "regex")]
+pub fn matches_any(field: &str, patterns: &[&'static str], value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must match one of the patterns: {}.", field, patterns.join(", "));
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+
+    // clone the compiled patterns out so the cache is not locked while matching
+    let compiled: Vec<Regex> = {
+        let mut cache = PATTERN_CACHE.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+        patterns
+            .iter()
+            .filter_map(|pattern| cache.entry(*pattern).or_insert_with(|| Regex::new(pattern).ok()).clone())
+            .collect()
+    };
+
+    let cond = compiled.iter().any(|re| re.is_match(v));
+    InnerValidationResult(cond, err)
+}
 
-    let v: String = extract_value(value);
-    let re = Regex::new(r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}$").unwrap();
-    InnerValidationResult(re.is_match(&v), err)
+/// error message of a string length or an int size outside of ```min``` and ```max```
+pub fn range_message<T: Display>(field: &str, min: &T, max: &T, range_type: &RangeType) -> String {
+    format!("{}'s {} must be between {} and {}.", field, range_type.to_string(), min, max)
 }
 
 /// Validates whether the ```length``` of a ```string``` or the ```size``` of an ```int``` is within a specified 
-/// range of ```min``` and ```max```.
+/// range of ```min``` and ```max```. Both bounds are inclusive.
 pub fn range<T>(
     field: &str,
     value: Value,
@@ -172,82 +482,2199 @@ pub fn range<T>(
     range_type: RangeType,
 ) -> InnerValidationResult
 where
-    T: DeserializeOwned + PartialOrd + Display + 'static + TryFrom<usize>,
-    <T as TryFrom<usize>>::Error: Debug,
+    T: DeserializeOwned + FromStr + PartialOrd + Display + 'static + TryFrom<usize>,
 {
-    let err = format!(
-        "{}'s {} must be between {} and {}.",
-        field,
-        range_type.to_string(),
-        min,
-        max
-    );
+    let err = range_message(field, min, max, &range_type);
 
-    if value.is_null() {
-        return InnerValidationResult(false, err);
-    }
+    let len: T = match range_type {
+        RangeType::Length => match value.as_str().map(|v| T::try_from(v.len())) {
+            Some(Ok(len)) => len,
+            _ => return InnerValidationResult(false, err),
+        },
+        RangeType::Size => match extract_number(value) {
+            Some(v) => v,
+            None => return InnerValidationResult(false, err),
+        },
+    };
+
+    let cond = within(&len, Bound::Included(min), Bound::Included(max));
+    InnerValidationResult(cond, err)
+}
 
-    let len: T;
+/// checks ```v``` against a lower and an upper bound, each inclusive, exclusive or unbounded
+fn within<T: PartialOrd>(v: &T, min: Bound<&T>, max: Bound<&T>) -> bool {
+    let above = match min {
+        Bound::Included(min) => v >= min,
+        Bound::Excluded(min) => v > min,
+        Bound::Unbounded => true,
+    };
 
-    match range_type {
-        RangeType::Length => {
-            let val: String = extract_value(value);
-            let nv = T::try_from(val.len()).unwrap();
-            len = nv;
-        }
-        RangeType::Size => len = extract_value(value),
+    let below = match max {
+        Bound::Included(max) => v <= max,
+        Bound::Excluded(max) => v < max,
+        Bound::Unbounded => true,
+    };
+
+    above && below
+}
+
+/// describes bounds for error messages, e.g. ```greater than 0 and at most 10```
+pub fn describe_bounds<T: Display>(min: &Bound<T>, max: &Bound<T>) -> String {
+    let lower = match min {
+        Bound::Included(min) => Some(format!("at least {}", min)),
+        Bound::Excluded(min) => Some(format!("greater than {}", min)),
+        Bound::Unbounded => None,
+    };
+
+    let upper = match max {
+        Bound::Included(max) => Some(format!("at most {}", max)),
+        Bound::Excluded(max) => Some(format!("less than {}", max)),
+        Bound::Unbounded => None,
+    };
+
+    match (lower, upper) {
+        (Some(lower), Some(upper)) => format!("{} and {}", lower, upper),
+        (Some(bound), None) | (None, Some(bound)) => bound,
+        (None, None) => "unbounded".to_string(),
     }
+}
+
+/// Validates that the length of a string is within ```min``` and ```max```, each of which may be inclusive,
+/// exclusive or unbounded.
+pub fn length_bounds(field: &str, value: Value, min: &Bound<usize>, max: &Bound<usize>) -> InnerValidationResult {
+    let err = format!("'{}' field's length must be {}.", field, describe_bounds(min, max));
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(within(&v.len(), min.as_ref(), max.as_ref()), err)
+}
+
+/// Validates that an integer (or numeric string) is within ```min``` and ```max```, each of which may be inclusive,
+/// exclusive or unbounded. Other values fail.
+pub fn size_bounds(field: &str, value: Value, min: &Bound<isize>, max: &Bound<isize>) -> InnerValidationResult {
+    let err = format!("'{}' field must be {}.", field, describe_bounds(min, max));
+    let cond = extract_number::<isize>(value).is_some_and(|v| within(&v, min.as_ref(), max.as_ref()));
+    InnerValidationResult(cond, err)
+}
 
-    let cond = &len > min && &len < max;
+/// Validates that a number (or numeric string) is within ```min``` and ```max```, each of which may be inclusive,
+/// exclusive or unbounded. Like the ```Float*``` rules, ```NaN```, infinities and other values fail.
+pub fn float_bounds(field: &str, value: Value, min: &Bound<f64>, max: &Bound<f64>) -> InnerValidationResult {
+    let err = format!("'{}' field must be {}.", field, describe_bounds(min, max));
+    let cond = finite_f64(&value).is_some_and(|v| within(&v, min.as_ref(), max.as_ref()));
     InnerValidationResult(cond, err)
 }
 
+/// reads a number or numeric string as a finite ```f64```. ```NaN``` and infinities are rejected.
+fn finite_f64(value: &Value) -> Option<f64> {
+    let v = match value {
+        Value::Number(n) => n.as_f64()?,
+        Value::String(s) => s.trim().parse().ok()?,
+        _ => return None,
+    };
+
+    v.is_finite().then_some(v)
+}
+
+/// Validates that a number is finite and greater than zero
+pub fn float_positive(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a positive number.", field);
+    InnerValidationResult(finite_f64(&value).is_some_and(|v| v > 0.0), err)
+}
+
+/// Validates that a number is finite and less than zero
+pub fn float_negative(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a negative number.", field);
+    InnerValidationResult(finite_f64(&value).is_some_and(|v| v < 0.0), err)
+}
+
+/// Validates that a number is finite and not zero (```-0.0``` is zero)
+pub fn float_non_zero(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a non-zero number.", field);
+    InnerValidationResult(finite_f64(&value).is_some_and(|v| v != 0.0), err)
+}
+
 pub fn contains(field: &str, rule: &str, value: Value) -> InnerValidationResult {
     let err = format!("'{}' field must contain  '{}'. Please check again.", field, rule);
-    if value.is_null() {
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+
+    let cond = v.contains(rule);
+
+    InnerValidationResult(cond, err)
+}
+
+/// Like [```contains```], but lowercases both sides before comparing.
+pub fn contains_ignore_case(field: &str, rule: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must contain '{}' (case-insensitive).", field, rule);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(v.to_lowercase().contains(&rule.to_lowercase()), err)
+}
+
+/// Validates that string value contains all of ```rule```. The error lists the missing substrings.
+pub fn contains_all(field: &str, rule: &[&str], value: Value) -> InnerValidationResult {
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, format!("'{}' field must contain '{}'.", field, rule.join("', '"))),
+    };
+    let missing: Vec<&str> = rule.iter().copied().filter(|s| !v.contains(s)).collect();
+
+    let err = format!("'{}' field must contain '{}'.", field, missing.join("', '"));
+    InnerValidationResult(missing.is_empty(), err)
+}
+
+/// Validates that string value contains at least one of ```rule```.
+pub fn contains_any(field: &str, rule: &[&str], value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must contain one of '{}'.", field, rule.join("', '"));
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(rule.iter().any(|s| v.contains(s)), err)
+}
+
+/// Validates that string doesn't contain any whitespace
+pub fn no_whitespace(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must not contain whitespace.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(!v.chars().any(char::is_whitespace), err)
+}
+
+/// Validates that string has no uppercase letters
+pub fn is_lowercase(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be lowercase.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(v == v.to_lowercase(), err)
+}
+
+/// Validates that string has no lowercase letters
+pub fn is_uppercase(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be uppercase.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(v == v.to_uppercase(), err)
+}
+
+/// Validates that string has no leading or trailing whitespace
+pub fn trimmed(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must not start or end with whitespace.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(v == v.trim(), err)
+}
+
+/// Validates that string has non-whitespace content
+pub fn non_empty(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must not be empty.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(!v.trim().is_empty(), err)
+}
+
+/// counts the whitespace separated words of a string
+fn count_words(v: &str) -> usize {
+    v.split_whitespace().count()
+}
+
+/// Validates that the number of words in a string is within ```min``` and ```max``` (inclusive)
+pub fn word_count(field: &str, value: Value, min: &usize, max: &usize) -> InnerValidationResult {
+    let err = format!("'{}' field must have between {} and {} words.", field, min, max);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let count = count_words(v);
+    InnerValidationResult(count >= *min && count <= *max, err)
+}
+
+/// Validates that a string has at least ```min``` words
+pub fn min_words(field: &str, min: &usize, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must have at least {} words.", field, min);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(count_words(v) >= *min, err)
+}
+
+/// Validates that a string has at most ```max``` words
+pub fn max_words(field: &str, max: &usize, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must have at most {} words.", field, max);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(count_words(v) <= *max, err)
+}
+
+/// Validates that string doesn't contain line breaks
+pub fn single_line(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a single line.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(!v.contains(['\n', '\r']), err)
+}
+
+/// number of days in ```month``` of ```year```, accounting for leap years
+#[cfg(feature = "regex")]
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(feature = "regex")]
+static DATETIME_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Validates RFC 3339 timestamps such as ```2023-04-03T10:15:30Z``` or ```2023-04-03T10:15:30.5+01:00```
+#[cfg(feature = "regex")]
+pub fn datetime(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid RFC 3339 date-time.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let re = DATETIME_REGEX.get_or_init(|| {
+        Regex::new(r"^(\d{4})-(\d{2})-(\d{2})[Tt ](\d{2}):(\d{2}):(\d{2})(\.\d+)?([Zz]|[+-](\d{2}):(\d{2}))$").unwrap()
+    });
+
+    let caps = match re.captures(v) {
+        Some(caps) => caps,
+        None => return InnerValidationResult(false, err),
+    };
+
+    let part = |i: usize| caps.get(i).map_or(0, |m| m.as_str().parse::<u32>().unwrap_or(u32::MAX));
+    let (year, month, day) = (part(1), part(2), part(3));
+
+    let cond = (1..=12).contains(&month)
+        && (1..=days_in_month(year, month)).contains(&day)
+        && part(4) <= 23
+        && part(5) <= 59
+        && part(6) <= 60 // leap second
+        && part(9) <= 23
+        && part(10) <= 59;
+
+    InnerValidationResult(cond, err)
+}
+
+#[cfg(feature = "regex")]
+static DATE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Validates calendar dates in ```YYYY-MM-DD``` form, checking the day against the month's length
+#[cfg(feature = "regex")]
+pub fn date(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid date in YYYY-MM-DD format.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let re = DATE_REGEX.get_or_init(|| Regex::new(r"^(\d{4})-(\d{2})-(\d{2})$").unwrap());
+
+    let caps = match re.captures(v) {
+        Some(caps) => caps,
+        None => return InnerValidationResult(false, err),
+    };
+
+    let part = |i: usize| caps[i].parse::<u32>().unwrap_or(u32::MAX);
+    let (year, month, day) = (part(1), part(2), part(3));
+
+    let cond = (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates 24-hour clock times in ```HH:MM``` or ```HH:MM:SS``` form (hour 0 to 23, minute and second 0 to 59)
+pub fn time(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid time in HH:MM or HH:MM:SS format.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let parts: Vec<&str> = v.split(':').collect();
+
+    let is_two_digits = |p: &&str| p.len() == 2 && p.chars().all(|c| c.is_ascii_digit());
+    if !matches!(parts.len(), 2 | 3) || !parts.iter().all(is_two_digits) {
         return InnerValidationResult(false, err);
     }
 
-    let v: String = extract_value(value);
+    let part = |i: usize| parts.get(i).map_or(0, |p| p.parse::<u32>().unwrap());
+    let cond = part(0) <= 23 && part(1) <= 59 && part(2) <= 59;
 
-    let cond = v.contains(rule);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates IANA time zone names such as ```Africa/Lagos``` or ```UTC``` against a bundled list. Names are matched
+/// case-sensitively.
+pub fn timezone(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid IANA time zone.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(codes::TIMEZONES.binary_search(&v).is_ok(), err)
+}
+
+/// checks a single cron field: a comma separated list of ```*```, values or ```a-b``` ranges within ```min..=max```,
+/// each optionally followed by a ```/step```
+fn is_cron_field(field: &str, min: u32, max: u32) -> bool {
+    let in_range = |v: &str| v.parse::<u32>().is_ok_and(|v| (min..=max).contains(&v));
+
+    field.split(',').all(|item| {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (item, None),
+        };
+
+        if step.is_some_and(|s| !s.parse::<u32>().is_ok_and(|s| s > 0)) {
+            return false;
+        }
+
+        if range == "*" {
+            return true;
+        }
+
+        match range.split_once('-') {
+            Some((start, end)) => match (start.parse::<u32>(), end.parse::<u32>()) {
+                (Ok(start), Ok(end)) => min <= start && start <= end && end <= max,
+                _ => false,
+            },
+            None => in_range(range),
+        }
+    })
+}
+
+/// Validates cron expressions of 5 fields (minute, hour, day of month, month, day of week) or 6 fields (with
+/// leading seconds). Fields may use ```*```, lists, ranges and steps, e.g. ```*/15 9-17 * * 1,3,5```. Month and day
+/// names aren't supported.
+pub fn cron(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid cron expression.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let fields: Vec<&str> = v.split_whitespace().collect();
+
+    // minute, hour, day of month, month and day of week (0 and 7 are Sunday)
+    let mut bounds = vec![(0, 59), (0, 23), (1, 31), (1, 12), (0, 7)];
+    if fields.len() == 6 {
+        bounds.insert(0, (0, 59));
+    }
+
+    let cond = fields.len() == bounds.len()
+        && fields.iter().zip(bounds).all(|(f, (min, max))| is_cron_field(f, min, max));
 
     InnerValidationResult(cond, err)
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_length() {
-        use super::*;
+/// Validates ISO 4217 currency codes. Codes are matched case-sensitively, so only uppercase codes (e.g. ```USD```) pass.
+pub fn currency_code(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid ISO 4217 currency code.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(codes::CURRENCY_CODES.binary_search(&v).is_ok(), err)
+}
 
-        let len_rule = 7;
-        let size_rule = -32;
-        let InnerValidationResult(len_status, _) =
-            length("name", &len_rule, Value::from("Olamide"), LengthType::Min); // length
-        let InnerValidationResult(size_status, _) =
-            size("age", &size_rule, Value::from(44), LengthType::Max); // size
-        let InnerValidationResult(req_status, _) = required("valid", Value::from(Some("yes"))); // required
-        let InnerValidationResult(bool_status, _) = check_bool("allow", Value::from(false)); // boolean
-        let InnerValidationResult(pass_status, _) =
-            password("password", Value::from("MyUniquPas@007"), 8); // password
-        let InnerValidationResult(email_status, _) = email("email", Value::from("MyUniquPas@007")); // email
+/// Validates ISO 3166-1 alpha-2 country codes. Codes are matched case-sensitively, so only uppercase codes (e.g. ```US```) pass.
+pub fn country_code(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid ISO 3166-1 alpha-2 country code.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(codes::COUNTRY_CODES.binary_search(&v).is_ok(), err)
+}
 
-        // range
-        let (min, max) = (8,16);
-        let InnerValidationResult(rlen_status, _) = range::<i32>("rlen", Value::from("TheRandomString"), &min, &max, RangeType::Length); // length
-        let InnerValidationResult(slen_status, _) = range("slen", Value::from(6), &min, &max, RangeType::Size); // size
+/// checks ```v``` against a format ```mask``` such as ```A9A 9A9```: ```9``` matches a digit, ```A``` an uppercase
+/// letter, ```?``` either, and any other char itself
+fn matches_mask(v: &str, mask: &str) -> bool {
+    v.len() == mask.len()
+        && v.chars().zip(mask.chars()).all(|(c, m)| match m {
+            '9' => c.is_ascii_digit(),
+            'A' => c.is_ascii_uppercase(),
+            '?' => c.is_ascii_digit() || c.is_ascii_uppercase(),
+            _ => c == m,
+        })
+}
 
-        let InnerValidationResult(cont_status, _) = contains("contains_field", "nothere", Value::from("I love rust")); // contains
+/// Validates a postal code in the format of ```country```, ignoring the case of letters. Countries without a known
+/// format accept 2 to 10 letters and digits, optionally separated by single spaces or hyphens.
+pub fn postal_code(field: &str, country: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid {} postal code.", field, country);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let v = v.trim().to_uppercase();
+    let country = country.to_uppercase();
+
+    let cond = match codes::POSTAL_CODE_FORMATS.binary_search_by(|(c, _)| (*c).cmp(country.as_str())) {
+        Ok(i) => codes::POSTAL_CODE_FORMATS[i].1.iter().any(|mask| matches_mask(&v, mask)),
+        Err(_) => {
+            let parts: Vec<&str> = v.split([' ', '-']).collect();
+            let len: usize = parts.iter().map(|p| p.len()).sum();
+            (2..=10).contains(&len) && parts.iter().all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_alphanumeric()))
+        }
+    };
+
+    InnerValidationResult(cond, err)
+}
+
+/// Validates the format of an EU VAT identification number: a country prefix followed by the digits and letters
+/// expected for that country. Spaces, dots and hyphens are ignored and letters are case-insensitive. Check digits
+/// aren't verified.
+pub fn vat_number(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid VAT number.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let v: String = v.chars().filter(|c| !matches!(c, ' ' | '.' | '-')).collect::<String>().to_uppercase();
+
+    if v.len() < 3 || !v.is_char_boundary(2) {
+        return InnerValidationResult(false, err);
+    }
+
+    let (country, number) = v.split_at(2);
+    let cond = match codes::VAT_FORMATS.binary_search_by(|(c, _)| (*c).cmp(country)) {
+        Ok(i) => codes::VAT_FORMATS[i].1.iter().any(|mask| matches_mask(number, mask)),
+        Err(_) => false,
+    };
+
+    InnerValidationResult(cond, err)
+}
+
+/// Validates an IBAN: the length expected for its country and its mod-97 checksum. Spaces are ignored.
+pub fn iban(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid IBAN.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let v: String = v.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase();
+
+    if v.len() < 4 || !v.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return InnerValidationResult(false, err);
+    }
+
+    let expected = codes::IBAN_LENGTHS
+        .binary_search_by(|(country, _)| (*country).cmp(&v[..2]))
+        .map(|i| codes::IBAN_LENGTHS[i].1);
+
+    if expected != Ok(v.len()) {
+        return InnerValidationResult(false, err);
+    }
+
+    // move the first four chars to the end, convert letters to numbers (A = 10) and compute mod 97
+    let rearranged = v[4..].chars().chain(v[..4].chars());
+    let remainder = rearranged.fold(0, |acc, c| {
+        let n = c.to_digit(36).unwrap();
+        let acc = if n >= 10 { acc * 100 + n } else { acc * 10 + n };
+        acc % 97
+    });
+
+    InnerValidationResult(remainder == 1, err)
+}
+
+/// Validates a port number in ```1..=65535```. Port ```0``` is reserved and fails. Numeric strings (e.g. ```"8080"```) are accepted.
+pub fn port(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a port number between 1 and 65535.", field);
+
+    let port = match &value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse::<u64>().ok(),
+        _ => None,
+    };
+
+    InnerValidationResult(port.is_some_and(|p| (1..=65535).contains(&p)), err)
+}
+
+/// checks a hostname label: 1 to 63 alphanumerics or hyphens, not starting or ending with a hyphen
+fn is_label(label: &str) -> bool {
+    (1..=63).contains(&label.len())
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+}
+
+/// checks an RFC 1123 hostname of at most 253 chars
+fn is_hostname(v: &str) -> bool {
+    v.len() <= 253 && v.split('.').all(is_label)
+}
+
+/// Validates RFC 1123 hostnames such as ```example.com``` or ```localhost```
+pub fn hostname(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid hostname.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(is_hostname(v), err)
+}
+
+/// checks a GTIN (EAN/UPC) of ```len``` digits: the weighted sum (3 and 1 alternating from the right, check digit
+/// excluded) plus the check digit must be a multiple of 10
+fn is_gtin(v: &str, len: usize) -> bool {
+    if v.len() != len || !v.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let sum: u32 = v
+        .chars()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| c.to_digit(10).unwrap() * if i % 2 == 1 { 3 } else { 1 })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+/// reads a digit code such as a barcode from a string or a non-negative integer (e.g. ```4006381333931```), returning
+/// ```None``` for other values. Leading zeros of integers are lost, so such codes must be sent as strings.
+fn digit_code(value: &Value) -> Option<String> {
+    match value {
+        Value::String(v) => Some(v.clone()),
+        Value::Number(n) if n.is_u64() => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Validates an EAN-13 barcode: 13 digits with a valid check digit. Integers are accepted.
+pub fn ean13(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid EAN-13 barcode.", field);
+    InnerValidationResult(digit_code(&value).is_some_and(|v| is_gtin(&v, 13)), err)
+}
+
+/// Validates a UPC-A barcode: 12 digits with a valid check digit. Integers are accepted.
+pub fn upc_a(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid UPC-A barcode.", field);
+    InnerValidationResult(digit_code(&value).is_some_and(|v| is_gtin(&v, 12)), err)
+}
+
+/// removes the hyphens and spaces separating ISBN groups, or ```None``` if value isn't a digit code
+fn strip_isbn(value: &Value) -> Option<String> {
+    let v = digit_code(value)?;
+    Some(v.chars().filter(|c| *c != '-' && !c.is_whitespace()).collect())
+}
+
+/// Validates an ISBN-10 such as ```0-8044-2957-X```: 9 digits and a mod-11 check digit, where ```X``` stands for 10.
+/// Hyphens and spaces are ignored and integers are accepted.
+pub fn isbn10(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid ISBN-10.", field);
+    let v = match strip_isbn(&value) {
+        Some(v) if v.len() == 10 => v,
+        _ => return InnerValidationResult(false, err),
+    };
+
+    let mut sum = 0;
+    for (i, c) in v.chars().enumerate() {
+        let digit = match c {
+            'X' | 'x' if i == 9 => 10,
+            _ => match c.to_digit(10) {
+                Some(d) => d,
+                None => return InnerValidationResult(false, err),
+            },
+        };
+
+        sum += digit * (10 - i as u32);
+    }
+
+    InnerValidationResult(sum.is_multiple_of(11), err)
+}
+
+/// Validates an ISBN-13 such as ```978-0-306-40615-7```: 13 digits and a mod-10 check digit. Hyphens and spaces
+/// are ignored and integers are accepted.
+pub fn isbn13(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid ISBN-13.", field);
+    InnerValidationResult(strip_isbn(&value).is_some_and(|v| is_gtin(&v, 13)), err)
+}
+
+/// Validates a ULID: 26 uppercase Crockford base32 chars (digits and letters except ```I```, ```L```, ```O``` and
+/// ```U```). The first char is at most ```7``` since the timestamp is 48 bits.
+pub fn ulid(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid ULID.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let cond = v.len() == 26
+        && v.starts_with(|c: char| ('0'..='7').contains(&c))
+        && v.chars().all(|c| c.is_ascii_digit() || (c.is_ascii_uppercase() && !"ILOU".contains(c)));
+
+    InnerValidationResult(cond, err)
+}
+
+/// Validates a UUID of the given ```version``` in its hyphenated form (e.g. ```550e8400-e29b-41d4-a716-446655440000```),
+/// checking the version nibble and the RFC 4122 variant bits.
+pub fn uuid_version(field: &str, version: &u8, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid version {} UUID.", field, version);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let groups: Vec<&str> = v.split('-').collect();
+    let is_uuid = groups.iter().map(|g| g.len()).eq([8, 4, 4, 4, 12])
+        && groups.iter().all(|g| g.chars().all(|c| c.is_ascii_hexdigit()));
+
+    if !is_uuid {
+        return InnerValidationResult(false, err);
+    }
+
+    let nibble = |i: usize| (v.as_bytes()[i] as char).to_digit(16).unwrap();
+    let cond = nibble(14) == *version as u32 && (8..=11).contains(&nibble(19));
+
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that every char of value is ASCII.
+pub fn ascii(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must contain only ASCII characters.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(v.is_ascii(), err)
+}
+
+/// Validates that every char of value is a printable ASCII char (```char::is_ascii_graphic``` or space), so control
+/// chars such as tabs and newlines fail.
+pub fn ascii_printable(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must contain only printable ASCII characters.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    InnerValidationResult(v.chars().all(|c| c.is_ascii_graphic() || c == ' '), err)
+}
+
+/// Validates the Luhn check digit of a digit string (e.g. IMEIs) or integer. Only the checksum is verified, not the
+/// length.
+pub fn luhn(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a number with a valid Luhn check digit.", field);
+    let v = match digit_code(&value) {
+        Some(v) if v.len() >= 2 && v.chars().all(|c| c.is_ascii_digit()) => v,
+        _ => return InnerValidationResult(false, err),
+    };
+
+    // double every second digit from the right, subtracting 9 from results above 9
+    let sum: u32 = v
+        .chars()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| {
+            let d = c.to_digit(10).unwrap();
+            match i % 2 {
+                1 if d * 2 > 9 => d * 2 - 9,
+                1 => d * 2,
+                _ => d,
+            }
+        })
+        .sum();
+
+    InnerValidationResult(sum.is_multiple_of(10), err)
+}
+
+/// counts the fractional digits of a decimal such as ```9.995``` or ```1.5e-3```, or ```None``` if it's not a number
+fn decimal_places(v: &str) -> Option<usize> {
+    if !v.parse::<f64>().is_ok_and(f64::is_finite) {
+        return None;
+    }
+
+    let (mantissa, exponent) = match v.find(['e', 'E']) {
+        Some(i) => (&v[..i], v[i + 1..].parse::<i64>().ok()?),
+        None => (v, 0),
+    };
+
+    let fraction = mantissa.split_once('.').map_or(0, |(_, f)| f.len()) as i64;
+    Some((fraction - exponent).max(0) as usize)
+}
+
+/// Validates that a number, or numeric string (e.g. ```"9.99"```), has at most ```rule``` decimal places.
+pub fn max_decimal_places(field: &str, rule: &usize, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a number with at most {} decimal places.", field, rule);
+
+    let places = match &value {
+        Value::Number(n) => decimal_places(&n.to_string()),
+        Value::String(s) => decimal_places(s),
+        _ => None,
+    };
+
+    InnerValidationResult(places.is_some_and(|p| p <= *rule), err)
+}
+
+/// parses a string value as JSON, returning ```None``` if it isn't a string of valid JSON
+fn parse_json(value: Value) -> Option<Value> {
+    match value {
+        Value::String(v) => serde_json::from_str(&v).ok(),
+        _ => None,
+    }
+}
+
+/// Validates that a string value is a JSON document whose top level is an array (e.g. ```"[1, 2]"```).
+pub fn json_array(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a JSON array.", field);
+    InnerValidationResult(parse_json(value).is_some_and(|v| v.is_array()), err)
+}
+
+/// Validates that a string value is a JSON document whose top level is an object (e.g. ```"{\"a\": 1}"```).
+pub fn json_object(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a JSON object.", field);
+    InnerValidationResult(parse_json(value).is_some_and(|v| v.is_object()), err)
+}
+
+/// Validates that the number of items in an array is within ```min``` and ```max``` (inclusive). Non-array values fail.
+pub fn array_length(field: &str, value: Value, min: &usize, max: &usize) -> InnerValidationResult {
+    match value {
+        Value::Array(items) => {
+            let err = format!("'{}' field must have between {} and {} items.", field, min, max);
+            InnerValidationResult(items.len() >= *min && items.len() <= *max, err)
+        }
+        _ => InnerValidationResult(false, format!("'{}' field must be an array.", field)),
+    }
+}
+
+/// Validates that no two items of an array are equal. Non-array values fail.
+pub fn unique_items(field: &str, value: Value) -> InnerValidationResult {
+    match value {
+        Value::Array(items) => {
+            let err = format!("'{}' field must not contain duplicate items.", field);
+            let cond = items.iter().enumerate().all(|(i, item)| !items[..i].contains(item));
+            InnerValidationResult(cond, err)
+        }
+        _ => InnerValidationResult(false, format!("'{}' field must be an array.", field)),
+    }
+}
+
+/// Validates that an array contains an item equal to ```rule```. Items are compared like [```equals```].
+pub fn array_contains(field: &str, rule: &str, value: Value) -> InnerValidationResult {
+    match value {
+        Value::Array(items) => {
+            let err = format!("'{}' field must contain '{}'.", field, rule);
+            InnerValidationResult(items.iter().any(|item| !item.is_null() && as_text(item) == rule), err)
+        }
+        _ => InnerValidationResult(false, format!("'{}' field must be an array.", field)),
+    }
+}
+
+/// Validates fully-qualified domain names such as ```example.com```: at least two hostname labels, the last being a
+/// TLD of 2 or more letters. Bare hostnames like ```localhost``` fail, while the absolute form with a trailing dot
+/// (```example.com.```) passes.
+pub fn domain(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid domain name.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let v = v.strip_suffix('.').unwrap_or(v);
+
+    let cond = match v.rsplit_once('.') {
+        Some((_, tld)) => is_hostname(v) && tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()),
+        None => false,
+    };
+
+    InnerValidationResult(cond, err)
+}
+
+/// Validates absolute ```http``` and ```https``` URLs such as ```https://example.com:8080/hooks?id=1```. Other schemes
+/// (```ftp```, ```mailto```, ...) fail. The host must be a hostname and the port, if any, a valid port number.
+pub fn http_url(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid http or https URL.", field);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let rest = match v.split_once("://") {
+        Some((scheme, rest)) if scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https") => rest,
+        _ => return InnerValidationResult(false, err),
+    };
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let (host, valid_port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse::<u16>().is_ok_and(|p| p > 0)),
+        None => (authority, true),
+    };
+
+    let cond = is_hostname(host) && valid_port && !v.chars().any(char::is_whitespace);
+    InnerValidationResult(cond, err)
+}
+
+/// checks the comma separated components of a css color function against ```checks```
+fn is_color_args(args: &str, checks: &[fn(&str) -> bool]) -> bool {
+    let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+    parts.len() == checks.len() && parts.iter().zip(checks).all(|(part, check)| check(part))
+}
+
+/// Validates css colors: hex (```#f00```, ```#ff000080```), ```rgb()```/```rgba()``` with channels of 0 to 255 and
+/// ```hsl()```/```hsla()``` with a hue of 0 to 360 and percentages of 0% to 100%. Alpha is a number from 0 to 1.
+pub fn css_color(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid css color (hex, rgb(), rgba(), hsl() or hsla()).", field);
+    let channel: fn(&str) -> bool = |v| v.parse::<u8>().is_ok();
+    let alpha: fn(&str) -> bool = |v| v.parse::<f64>().is_ok_and(|a| (0.0..=1.0).contains(&a));
+    let hue: fn(&str) -> bool = |v| v.parse::<f64>().is_ok_and(|h| (0.0..=360.0).contains(&h));
+    let percent: fn(&str) -> bool =
+        |v| v.strip_suffix('%').is_some_and(|p| p.parse::<f64>().is_ok_and(|p| (0.0..=100.0).contains(&p)));
+
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let v = v.trim().to_lowercase();
+
+    let function = |name: &str| v.strip_prefix(name).and_then(|rest| rest.strip_prefix('(')?.strip_suffix(')'));
+    let cond = if let Some(hex) = v.strip_prefix('#') {
+        matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some(args) = function("rgba") {
+        is_color_args(args, &[channel, channel, channel, alpha])
+    } else if let Some(args) = function("rgb") {
+        is_color_args(args, &[channel, channel, channel])
+    } else if let Some(args) = function("hsla") {
+        is_color_args(args, &[hue, percent, percent, alpha])
+    } else if let Some(args) = function("hsl") {
+        is_color_args(args, &[hue, percent, percent])
+    } else {
+        false
+    };
+
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that a file name's extension (after the last dot) is one of ```rule```, ignoring case. Names without an
+/// extension and dotfiles such as ```.env``` fail. Only the last extension counts, so ```archive.tar.gz``` is ```gz```.
+pub fn file_extension(field: &str, rule: &[&str], value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must have one of the extensions: {}.", field, rule.join(", "));
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let cond = match v.rsplit_once('.') {
+        Some((name, ext)) if !name.is_empty() => rule.iter().any(|r| r.eq_ignore_ascii_case(ext)),
+        _ => false,
+    };
+
+    InnerValidationResult(cond, err)
+}
+
+/// checks an RFC 6838 name of a media type: 1 to 127 alphanumerics or ```!#$&-^_.+```, starting with an alphanumeric
+fn is_media_name(name: &str) -> bool {
+    (1..=127).contains(&name.len())
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+}
+
+/// Validates MIME types in ```type/subtype``` form, optionally followed by parameters such as
+/// ```; charset=utf-8```. The top-level type must be a registered one (```application```, ```text```, ...).
+pub fn mime_type(field: &str, value: Value) -> InnerValidationResult {
+    let err = format!("'{}' field must be a valid MIME type.", field);
+    const TYPES: [&str; 10] =
+        ["application", "audio", "example", "font", "image", "message", "model", "multipart", "text", "video"];
+
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+    let mut parts = v.split(';').map(str::trim);
+
+    let essence = parts.next().unwrap_or_default().to_lowercase();
+    let valid_essence = match essence.split_once('/') {
+        Some((kind, subtype)) => TYPES.contains(&kind) && is_media_name(subtype),
+        None => false,
+    };
+
+    let valid_params = parts.all(|param| match param.split_once('=') {
+        Some((name, value)) => is_media_name(name) && !value.is_empty(),
+        None => false,
+    });
+
+    InnerValidationResult(valid_essence && valid_params, err)
+}
+
+/// Validates a field against the fields it depends on using a user defined ```check```.
+pub fn depends_on(
+    field: &str,
+    value: Value,
+    fields: &[&str],
+    check: fn(&Value, &[&Value]) -> bool,
+    map: &Map<String, Value>,
+) -> InnerValidationResult {
+    let err = format!("'{}' field is inconsistent with '{}'.", field, fields.join("', '"));
+
+    let null = Value::Null;
+    let deps: Vec<&Value> = fields
+        .iter()
+        .map(|f| lookup(map, f).unwrap_or(&null))
+        .collect();
+
+    InnerValidationResult(check(&value, &deps), err)
+}
+
+/// Validates a size in bytes against the limit of the MIME type held by ```mime_field```.
+/// 
+/// Fails if the MIME type has no declared limit, or if the size isn't a non-negative integer.
+pub fn size_limit_by_mime(
+    field: &str,
+    value: Value,
+    mime_field: &str,
+    limits: &[(&str, u64)],
+    map: &Map<String, Value>,
+) -> InnerValidationResult {
+    let mime = lookup(map, mime_field).and_then(|m| m.as_str()).unwrap_or_default();
+    let limit = limits.iter().find(|(m, _)| *m == mime).map(|(_, l)| *l);
+
+    let limit = match limit {
+        Some(limit) => limit,
+        None => {
+            let err = format!("'{}' field has no size limit declared for '{}'.", field, mime);
+            return InnerValidationResult(false, err);
+        }
+    };
+
+    let err = format!(
+        "'{}' field must be a maximum of {} bytes for '{}'.",
+        field, limit, mime
+    );
+
+    let cond = value.as_u64().is_some_and(|v| v <= limit);
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that value is equal to the value of ```other``` field.
+pub fn match_field(field: &str, value: Value, other: &str, map: &Map<String, Value>) -> InnerValidationResult {
+    let err = format!("'{}' field must match '{}'.", field, other);
+    let cond = lookup(map, other).unwrap_or(&Value::Null) == &value;
+    InnerValidationResult(cond, err)
+}
+
+/// Returns whether ```other``` field equals ```expected```, compared like [```equals```]. Missing and null fields
+/// never match.
+pub fn field_equals(other: &str, expected: &str, map: &Map<String, Value>) -> bool {
+    match lookup(map, other) {
+        None | Some(Value::Null) => false,
+        Some(v) => as_text(v) == expected,
+    }
+}
+
+/// Validates that value differs from the value of ```other``` field.
+pub fn different_field(field: &str, value: Value, other: &str, map: &Map<String, Value>) -> InnerValidationResult {
+    let err = format!("'{}' field must be different from '{}'.", field, other);
+    let cond = lookup(map, other).unwrap_or(&Value::Null) != &value;
+    InnerValidationResult(cond, err)
+}
+
+/// Validates that value is not null when ```other``` field is present and not null.
+pub fn required_with(field: &str, value: Value, other: &str, map: &Map<String, Value>) -> InnerValidationResult {
+    let err = format!("'{}' field is required when '{}' is present.", field, other);
+    let triggered = !lookup(map, other).unwrap_or(&Value::Null).is_null();
+    InnerValidationResult(!triggered || !value.is_null(), err)
+}
+
+/// Validates that value is not null when ```other``` field is missing or null.
+pub fn required_without(field: &str, value: Value, other: &str, map: &Map<String, Value>) -> InnerValidationResult {
+    let err = format!("'{}' field is required when '{}' is not present.", field, other);
+    let triggered = lookup(map, other).unwrap_or(&Value::Null).is_null();
+    InnerValidationResult(!triggered || !value.is_null(), err)
+}
+
+/// computes the Levenshtein distance between two strings
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = cost.min(row[j] + 1).min(prev + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// describes the bounds of an edit distance for error messages, e.g. ```between 1 and 3```
+pub fn describe_distance(min: Option<usize>, max: Option<usize>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) => format!("between {} and {}", min, max),
+        (Some(min), None) => format!("{} {}", LengthType::Min.to_string(), min),
+        (None, Some(max)) => format!("{} {}", LengthType::Max.to_string(), max),
+        (None, None) => String::from("any value"),
+    }
+}
+
+/// Validates that the edit (Levenshtein) distance between value and ```other``` field is within ```min``` and ```max```.
+pub fn edit_distance(
+    field: &str,
+    value: Value,
+    other: &str,
+    min: Option<usize>,
+    max: Option<usize>,
+    map: &Map<String, Value>,
+) -> InnerValidationResult {
+    let bounds = describe_distance(min, max);
+    let err = format!("'{}' field's distance from '{}' must be {}.", field, other, bounds);
+    let v = match value.as_str() {
+        Some(v) => v,
+        None => return InnerValidationResult(false, err),
+    };
+
+    let o = lookup(map, other).and_then(|o| o.as_str()).unwrap_or_default();
+    let distance = levenshtein(v, o);
+
+    let err = format!("'{}' field's distance from '{}' must be {}, got {}.", field, other, bounds, distance);
+    let cond = min.is_none_or(|min| distance >= min) && max.is_none_or(|max| distance <= max);
+
+    InnerValidationResult(cond, err)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_length() {
+        use super::*;
+
+        let len_rule = 7;
+        let size_rule = -32;
+        let InnerValidationResult(len_status, _) =
+            length("name", &len_rule, Value::from("Olamide"), LengthType::Min); // length
+        let InnerValidationResult(size_status, _) =
+            size("age", &size_rule, Value::from(44), LengthType::Max); // size
+        let InnerValidationResult(req_status, _) = required("valid", Value::from(Some("yes"))); // required
+        let InnerValidationResult(bool_status, _) = check_bool("allow", Value::from(false)); // boolean
+        let InnerValidationResult(pass_status, _) =
+            password("password", Value::from("MyUniquPas@007"), 8); // password
+        let InnerValidationResult(email_status, _) = email("email", Value::from("MyUniquPas@007")); // email
+
+        // range
+        let (min, max) = (8,16);
+        let InnerValidationResult(rlen_status, _) = range::<i32>("rlen", Value::from("TheRandomString"), &min, &max, RangeType::Length); // length
+        let InnerValidationResult(slen_status, _) = range("slen", Value::from(6), &min, &max, RangeType::Size); // size
+        let InnerValidationResult(smin_status, _) = range("smin", Value::from(8), &min, &max, RangeType::Size); // lower bound
+        let InnerValidationResult(smax_status, _) = range("smax", Value::from(16), &min, &max, RangeType::Size); // upper bound
+        let InnerValidationResult(lmax_status, _) = range::<i32>("lmax", Value::from("SixteenCharsLong"), &min, &max, RangeType::Length); // upper bound
+
+        let InnerValidationResult(cont_status, _) = contains("contains_field", "nothere", Value::from("I love rust")); // contains
+
+        assert!(len_status);
+        assert!(!size_status);
+        assert!(req_status);
+        assert!(bool_status);
+        assert!(pass_status);
+        assert!(!email_status);
+        assert!(rlen_status);
+        assert!(!slen_status);
+        assert!(smin_status);
+        assert!(smax_status);
+        assert!(lmax_status);
+        assert!(!cont_status);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_matches_any() {
+        use super::*;
+
+        let patterns = &[r"^EMP-\d{4}$", r"^[A-Z]{2}\d{6}$", r"^\d{9}$"];
+        assert!(matches_any("employee_id", patterns, Value::from("AB123456")).0);
+        // the second call uses the cached patterns
+        assert!(matches_any("employee_id", patterns, Value::from("123456789")).0);
+        assert!(!matches_any("employee_id", &["(unclosed", r"^\d{9}$"], Value::from("AB123456")).0);
+
+        let InnerValidationResult(status, err) = matches_any("employee_id", patterns, Value::from("EMP-12"));
+        assert!(!status);
+        assert_eq!(err, r"'employee_id' field must match one of the patterns: ^EMP-\d{4}$, ^[A-Z]{2}\d{6}$, ^\d{9}$.");
+        assert!(!matches_any("employee_id", patterns, Value::Null).0);
+        assert!(!matches_any("employee_id", patterns, Value::from(123456789)).0);
+        assert!(!matches_any("employee_id", patterns, Value::from(vec!["AB123456"])).0);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_email_batch() {
+        use super::*;
+
+        // the regex is compiled once and reused across the whole batch
+        let valid = (0..10_000)
+            .map(|i| email("email", Value::from(format!("user{}@example.com", i))))
+            .filter(|InnerValidationResult(status, _)| *status)
+            .count();
+
+        assert_eq!(valid, 10_000);
+
+        let InnerValidationResult(status, _) = email("email", Value::from("user@examplecom"));
+        assert!(!status);
+    }
+
+    #[test]
+    fn test_size_limit_by_mime() {
+        use super::*;
+
+        const LIMITS: &[(&str, u64)] = &[("image/png", 5_000_000), ("video/mp4", 100_000_000)];
+
+        let image = serde_json::json!({ "mime": "image/png", "size": 2_000_000 });
+        let video = serde_json::json!({ "mime": "video/mp4", "size": 150_000_000 });
+
+        let InnerValidationResult(image_status, _) =
+            size_limit_by_mime("size", image["size"].clone(), "mime", LIMITS, image.as_object().unwrap());
+        let InnerValidationResult(video_status, video_err) =
+            size_limit_by_mime("size", video["size"].clone(), "mime", LIMITS, video.as_object().unwrap());
+
+        assert!(image_status);
+        assert!(!video_status);
+        assert!(video_err.contains("100000000") && video_err.contains("video/mp4"));
+
+        // sizes that aren't non-negative integers fail instead of panicking
+        let map = image.as_object().unwrap();
+        assert!(!size_limit_by_mime("size", Value::from(-1), "mime", LIMITS, map).0);
+        assert!(!size_limit_by_mime("size", Value::from("100"), "mime", LIMITS, map).0);
+        assert!(!size_limit_by_mime("size", Value::from(1.5), "mime", LIMITS, map).0);
+        assert!(!size_limit_by_mime("size", Value::Null, "mime", LIMITS, map).0);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        use super::*;
+
+        let data = serde_json::json!({ "name": "kitten", "near": "sitting", "far": "puppies" });
+        let map = data.as_object().unwrap();
+
+        let InnerValidationResult(near_status, _) =
+            edit_distance("near", data["near"].clone(), "name", None, Some(3), map);
+        let InnerValidationResult(far_status, far_err) =
+            edit_distance("far", data["far"].clone(), "name", None, Some(3), map);
+        let InnerValidationResult(min_status, _) =
+            edit_distance("near", data["near"].clone(), "name", Some(4), None, map);
+
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert!(near_status);
+        assert!(!far_status);
+        assert!(far_err.ends_with("got 6."));
+        assert!(!min_status);
+
+        // non-string values fail instead of panicking
+        assert!(!edit_distance("near", Value::from(7), "name", None, None, map).0);
+        assert!(!edit_distance("near", serde_json::json!(["kitten"]), "name", None, None, map).0);
+        assert!(!edit_distance("near", Value::Null, "name", None, None, map).0);
+    }
+
+    #[test]
+    fn test_password_policy() {
+        use super::*;
+
+        let passphrase = PasswordPolicy {
+            require_special: false,
+            require_digit: false,
+            allow_whitespace: true,
+            ..PasswordPolicy::new(16)
+        };
+
+        let InnerValidationResult(phrase_status, _) =
+            password_policy("password", Value::from("Correct horse battery staple"), &passphrase);
+        let InnerValidationResult(short_status, err) =
+            password_policy("password", Value::from("Correct horse"), &passphrase);
+        let InnerValidationResult(strict_status, strict_err) =
+            password("password", Value::from("Correct horse battery staple"), 8);
+
+        assert!(phrase_status);
+        assert!(!short_status);
+        assert_eq!(err, "'password' field must contain at least one uppercase letter and one lowercase letter and must be at least 16 chars long.");
+        assert!(!strict_status);
+        assert_eq!(strict_err, "'password' field must contain at least one uppercase letter, one lowercase letter, one digit and one special character and must be at least 8 chars long.");
+    }
+
+    #[test]
+    fn test_password_strength() {
+        use super::*;
+
+        let passwords = ["", "a", "abc", "abcdef", "abcDEF", "abcDEF12", "abcDEF12!?", "abcDEF12!?xyzQRS"];
+        let scores: Vec<u8> = passwords.iter().map(|p| password_strength(p)).collect();
+        assert!(scores.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", scores);
+        assert_eq!(scores[0], 0);
+        assert_eq!(password_strength("abcDEF12!?xyzQRS+-long"), 100);
+
+        // repetition is penalized
+        assert!(password_strength("aaaaaaaa") < password_strength("abcdefgh"));
+
+        assert!(min_password_strength("password", Value::from("abcDEF12!?"), 60).0);
+        assert!(!min_password_strength("password", Value::from("abcdef"), 60).0);
+        assert!(!min_password_strength("password", Value::Null, 0).0);
+    }
+
+    #[test]
+    fn test_password_max_length() {
+        use super::*;
+
+        let policy = PasswordPolicy { max_length: Some(128), ..PasswordPolicy::new(8) };
+
+        let long = format!("Aa1@{}", "x".repeat(296));
+        let InnerValidationResult(long_status, err) = password_policy("password", Value::from(long), &policy);
+        let InnerValidationResult(ok_status, _) = password_policy("password", Value::from("WhatAPass@003"), &policy);
+
+        assert!(!long_status);
+        assert!(ok_status);
+        assert!(err.ends_with("must be between 8 and 128 chars long."));
+        assert!(!password_policy("password", Value::from(12345678), &policy).0);
+    }
+
+    #[test]
+    fn test_parity() {
+        use super::*;
+
+        let is_even = |v: Value| even("batch", v).0;
+        let is_odd = |v: Value| odd("batch", v).0;
+
+        assert!(is_even(Value::from(4)));
+        assert!(is_even(Value::from(-4)));
+        assert!(is_even(Value::from(0)));
+        assert!(!is_even(Value::from(7)));
+        assert!(!is_even(Value::Null));
+
+        assert!(is_odd(Value::from(7)));
+        assert!(is_odd(Value::from(-7)));
+        assert!(!is_odd(Value::from(0)));
+        assert!(!is_odd(Value::from(-4)));
+        assert!(!is_odd(Value::Null));
+
+        assert!(is_even(Value::from("4")));
+        assert!(is_odd(Value::from("7")));
+        assert!(!is_even(Value::from(4.0)));
+        assert!(!is_odd(Value::from(3.5)));
+        assert!(!is_even(Value::from("abc")));
+        assert!(!is_odd(Value::from(true)));
+    }
+
+    #[test]
+    fn test_step_from() {
+        use super::*;
+
+        // minutes on a 15-minute grid starting at 9:00 (540)
+        assert!(step_from("slot", 540, 15, Value::from(540)).0);
+        assert!(step_from("slot", 540, 15, Value::from(585)).0);
+        assert!(step_from("slot", 540, 15, Value::from(525)).0);
+        assert!(!step_from("slot", 540, 15, Value::from(550)).0);
+        assert!(!step_from("slot", 540, 15, Value::Null).0);
+        assert!(step_from("slot", 540, 15, Value::from("585")).0);
+        assert!(!step_from("slot", 540, 15, Value::from(585.0)).0);
+        assert!(!step_from("slot", 540, 15, Value::from("abc")).0);
+
+        let InnerValidationResult(status, err) = step_from("slot", 540, 0, Value::from(540));
+        assert!(!status);
+        assert_eq!(err, "'slot' field cannot be validated with a step of 0.");
+    }
+
+    #[test]
+    fn test_multiple_of() {
+        use super::*;
+
+        let InnerValidationResult(divisible, _) = multiple_of("price", &5, Value::from(125));
+        let InnerValidationResult(negative, _) = multiple_of("price", &5, Value::from(-15));
+        let InnerValidationResult(not_divisible, _) = multiple_of("price", &5, Value::from(123));
+        let InnerValidationResult(zero, zero_err) = multiple_of("price", &0, Value::from(10));
+
+        assert!(divisible);
+        assert!(negative);
+        assert!(!not_divisible);
+        assert!(!zero);
+        assert_eq!(zero_err, "'price' field cannot be validated as a multiple of 0.");
+
+        assert!(multiple_of("price", &5, Value::from("125")).0);
+        assert!(multiple_of("price", &-1, Value::from(isize::MIN as i64)).0);
+        assert!(!multiple_of("price", &5, Value::from(125.0)).0);
+        assert!(!multiple_of("price", &5, Value::from("abc")).0);
+        assert!(!multiple_of("price", &5, Value::Null).0);
+    }
+
+    #[test]
+    fn test_equals() {
+        use super::*;
+
+        assert!(equals("confirm", "DELETE", Value::from("DELETE")).0);
+        assert!(!equals("confirm", "DELETE", Value::from("delete")).0);
+        assert!(equals("count", "42", Value::from(42)).0);
+        assert!(equals("allow", "true", Value::from(true)).0);
+        assert!(!equals("confirm", "DELETE", Value::Null).0);
+
+        assert!(not_equals("username", "admin", Value::from("prodbyola")).0);
+        assert!(!not_equals("username", "admin", Value::from("admin")).0);
+        assert!(!not_equals("count", "0", Value::from(0)).0);
+        assert!(!not_equals("username", "admin", Value::Null).0);
+    }
+
+    #[test]
+    fn test_enum_variant() {
+        use super::*;
+
+        let variants = ["admin", "editor"];
+        assert!(enum_variant("role", &variants, Value::from("editor")).0);
+        assert!(!enum_variant("role", &variants, Value::from("Editor")).0);
+        assert!(!enum_variant("role", &variants, Value::from(1)).0);
+        assert!(!enum_variant("role", &variants, Value::Null).0);
+    }
+
+    #[test]
+    fn test_strict_comparison() {
+        use super::*;
+
+        // the boundary fails strict comparisons...
+        assert!(!greater_than("age", &18, Value::from(18)).0);
+        assert!(!less_than("age", &65, Value::from(65)).0);
+
+        // ...but passes the inclusive ones
+        assert!(size("age", &18, Value::from(18), LengthType::Min).0);
+        assert!(size("age", &65, Value::from(65), LengthType::Max).0);
+
+        assert!(greater_than("age", &18, Value::from(19)).0);
+        assert!(less_than("age", &65, Value::from(64)).0);
+        assert!(!greater_than("age", &18, Value::Null).0);
+    }
+
+    #[test]
+    fn test_strict_comparison_non_integers() {
+        use super::*;
+
+        assert!(greater_than("age", &18, Value::from("42")).0);
+        assert!(less_than("age", &65, Value::from("42")).0);
+
+        // unparseable values fail instead of panicking
+        assert!(!greater_than("age", &18, Value::from("abc")).0);
+        assert!(!less_than("age", &65, Value::from("abc")).0);
+        assert!(!greater_than("age", &18, Value::from(42.5)).0);
+        assert!(!less_than("age", &65, Value::from(true)).0);
+    }
+
+    #[test]
+    fn test_size_numeric_strings() {
+        use super::*;
+
+        assert!(size("age", &18, Value::from("18"), LengthType::Exact).0);
+        assert!(size("age", &18, Value::from(" 42 "), LengthType::Min).0);
+        assert!(!size("age", &18, Value::from("12"), LengthType::Min).0);
+        assert!(size("age", &65, Value::from("-3"), LengthType::Max).0);
+        assert!(range("age", Value::from("42"), &18, &65, RangeType::Size).0);
+        assert!(!range("age", Value::from("70"), &18, &65, RangeType::Size).0);
+
+        // unparseable values fail instead of panicking
+        assert!(!size("age", &18, Value::from("forty-two"), LengthType::Min).0);
+        assert!(!size("age", &18, Value::from("42.5"), LengthType::Min).0);
+        assert!(!size("age", &18, Value::from(true), LengthType::Min).0);
+        assert!(!range("age", Value::from("abc"), &18, &65, RangeType::Size).0);
+        assert!(!range("age", serde_json::json!([42]), &18, &65, RangeType::Size).0);
+    }
+
+    #[test]
+    fn test_no_whitespace() {
+        use super::*;
+
+        assert!(no_whitespace("username", Value::from("prodbyola")).0);
+        assert!(!no_whitespace("username", Value::from("prod byola")).0);
+        assert!(!no_whitespace("username", Value::from("prod\tbyola")).0);
+        assert!(!no_whitespace("username", Value::from("prodbyola\n")).0);
+        assert!(!no_whitespace("username", Value::Null).0);
+        assert!(!no_whitespace("username", Value::from(5)).0);
+    }
+
+    #[test]
+    fn test_case() {
+        use super::*;
+
+        assert!(is_lowercase("slug", Value::from("free-val_01")).0);
+        assert!(!is_lowercase("slug", Value::from("Free-val")).0);
+        assert!(is_uppercase("code", Value::from("NGN-01")).0);
+        assert!(!is_uppercase("code", Value::from("NGn")).0);
+        assert!(!is_lowercase("slug", Value::Null).0);
+        assert!(!is_uppercase("code", Value::Null).0);
+        assert!(!is_lowercase("slug", Value::from(true)).0);
+        assert!(!is_uppercase("code", Value::from(42)).0);
+    }
+
+    #[test]
+    fn test_trimmed() {
+        use super::*;
+
+        assert!(trimmed("title", Value::from("I love rust")).0);
+        assert!(!trimmed("title", Value::from(" I love rust")).0);
+        assert!(!trimmed("title", Value::from("I love rust\t")).0);
+        assert!(!trimmed("title", Value::from(" I love rust ")).0);
+        assert!(!trimmed("title", Value::Null).0);
+        assert!(!trimmed("title", Value::from(42)).0);
+    }
+
+    #[test]
+    fn test_non_empty() {
+        use super::*;
+
+        assert!(!non_empty("bio", Value::from("")).0);
+        assert!(!non_empty("bio", Value::from("   ")).0);
+        assert!(non_empty("bio", Value::from("x")).0);
+        assert!(!non_empty("bio", Value::Null).0);
+        assert!(!non_empty("bio", Value::from(vec![1])).0);
+
+        // unlike non_empty, a minimum length counts whitespace
+        assert!(length("bio", &1, Value::from("   "), LengthType::Min).0);
+    }
+
+    #[test]
+    fn test_word_count() {
+        use super::*;
+
+        let (min, max) = (1, 3);
+        assert!(!word_count("bio", Value::from(""), &min, &max).0);
+        assert!(word_count("bio", Value::from("rust"), &min, &max).0);
+        assert!(word_count("bio", Value::from("I   love \t rust"), &min, &max).0);
+        assert!(!word_count("bio", Value::from("I love rust so much"), &min, &max).0);
+        assert!(!word_count("bio", Value::Null, &min, &max).0);
+        assert!(!word_count("bio", Value::from(42), &min, &max).0);
+    }
+
+    #[test]
+    fn test_min_max_words() {
+        use super::*;
+
+        assert!(!min_words("bio", &2, Value::from("rust")).0);
+        assert!(min_words("bio", &2, Value::from("love  rust")).0);
+        assert!(min_words("bio", &2, Value::from("I love rust")).0);
+        assert!(!min_words("bio", &2, Value::Null).0);
+        assert!(!min_words("bio", &2, Value::from(true)).0);
+
+        assert!(max_words("bio", &2, Value::from("rust")).0);
+        assert!(max_words("bio", &2, Value::from("love\trust")).0);
+        assert!(!max_words("bio", &2, Value::from("I love rust")).0);
+        assert!(!max_words("bio", &2, Value::Null).0);
+        assert!(!max_words("bio", &2, Value::from(42)).0);
+    }
+
+    #[test]
+    fn test_float_sign() {
+        use super::*;
+
+        for zero in [Value::from(0.0), Value::from(-0.0), Value::from("-0.0")] {
+            assert!(!float_positive("amount", zero.clone()).0);
+            assert!(!float_negative("amount", zero.clone()).0);
+            assert!(!float_non_zero("amount", zero).0);
+        }
+
+        // NaN can't be a JSON number, so it arrives as null or a string
+        for invalid in [Value::from(f64::NAN), Value::from("NaN"), Value::from("inf"), Value::from("-inf")] {
+            assert!(!float_positive("amount", invalid.clone()).0);
+            assert!(!float_negative("amount", invalid.clone()).0);
+            assert!(!float_non_zero("amount", invalid).0);
+        }
+
+        assert!(float_positive("amount", Value::from(12.5)).0);
+        assert!(float_positive("amount", Value::from("0.01")).0);
+        assert!(!float_positive("amount", Value::from(-12.5)).0);
+        assert!(float_negative("amount", Value::from(-0.01)).0);
+        assert!(!float_negative("amount", Value::from(3)).0);
+        assert!(float_non_zero("amount", Value::from(-3.2)).0);
+        assert!(!float_non_zero("amount", Value::from("abc")).0);
+    }
+
+    #[test]
+    fn test_single_line() {
+        use super::*;
+
+        assert!(single_line("title", Value::from("I love rust")).0);
+        assert!(!single_line("title", Value::from("I love\nrust")).0);
+        assert!(!single_line("title", Value::from("I love\r\nrust")).0);
+        assert!(!single_line("title", Value::Null).0);
+        assert!(!single_line("title", Value::from(1.5)).0);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_datetime() {
+        use super::*;
+
+        assert!(datetime("at", Value::from("2023-04-03T10:15:30Z")).0);
+        assert!(datetime("at", Value::from("2023-04-03T10:15:30.123+01:00")).0);
+        assert!(datetime("at", Value::from("2024-02-29 23:59:60-05:30")).0);
+        assert!(!datetime("at", Value::from("2023-13-03T10:15:30Z")).0); // bad month
+        assert!(!datetime("at", Value::from("2023-04-31T10:15:30Z")).0); // bad day
+        assert!(!datetime("at", Value::from("2023-04-03T24:15:30Z")).0); // bad hour
+        assert!(!datetime("at", Value::from("2023-04-03T10:15:30")).0); // missing timezone
+        assert!(!datetime("at", Value::Null).0);
+        assert!(!datetime("at", Value::from(1680516930)).0);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_date() {
+        use super::*;
+
+        assert!(date("dob", Value::from("2024-02-29")).0); // leap year
+        assert!(date("dob", Value::from("2000-02-29")).0); // divisible by 400
+        assert!(!date("dob", Value::from("2023-02-29")).0);
+        assert!(!date("dob", Value::from("1900-02-29")).0); // divisible by 100
+        assert!(!date("dob", Value::from("2023-00-10")).0);
+        assert!(!date("dob", Value::from("2023-13-10")).0);
+        assert!(!date("dob", Value::from("2023-04-31")).0);
+        assert!(!date("dob", Value::from("2023-04-00")).0);
+        assert!(!date("dob", Value::from("2023-4-3")).0);
+        assert!(!date("dob", Value::Null).0);
+        assert!(!date("dob", Value::from(20230403)).0);
+    }
+
+    #[test]
+    fn test_currency_code() {
+        use super::*;
+
+        assert!(codes::CURRENCY_CODES.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(currency_code("currency", Value::from("NGN")).0);
+        assert!(currency_code("currency", Value::from("USD")).0);
+        assert!(!currency_code("currency", Value::from("usd")).0); // lowercase fails
+        assert!(!currency_code("currency", Value::from("ABC")).0);
+        assert!(!currency_code("currency", Value::Null).0);
+        assert!(!currency_code("currency", Value::from(840)).0);
+    }
+
+    #[test]
+    fn test_country_code() {
+        use super::*;
+
+        assert!(codes::COUNTRY_CODES.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(country_code("country", Value::from("US")).0);
+        assert!(country_code("country", Value::from("NG")).0);
+        assert!(!country_code("country", Value::from("ng")).0); // lowercase fails
+        assert!(!country_code("country", Value::from("ZZ")).0);
+        assert!(!country_code("country", Value::from("USA")).0);
+        assert!(!country_code("country", Value::Null).0);
+        assert!(!country_code("country", Value::from(566)).0);
+    }
+
+    #[test]
+    fn test_iban() {
+        use super::*;
+
+        assert!(codes::IBAN_LENGTHS.windows(2).all(|w| w[0].0 < w[1].0));
+
+        assert!(iban("iban", Value::from("GB82 WEST 1234 5698 7654 32")).0);
+        assert!(iban("iban", Value::from("DE89370400440532013000")).0);
+        assert!(!iban("iban", Value::from("GB82 WEST 1234 5698 7654 33")).0); // broken check digit
+        assert!(!iban("iban", Value::from("GB82 WEST 1234 5698 7654")).0); // wrong length
+        assert!(!iban("iban", Value::from("ZZ82 WEST 1234 5698 7654 32")).0); // unknown country
+        assert!(!iban("iban", Value::Null).0);
+        assert!(!iban("iban", Value::from(vec!["GB82WEST12345698765432"])).0);
+    }
+
+    #[test]
+    fn test_port() {
+        use super::*;
+
+        assert!(!port("port", Value::from(0)).0);
+        assert!(port("port", Value::from(80)).0);
+        assert!(port("port", Value::from(65535)).0);
+        assert!(!port("port", Value::from(70000)).0);
+        assert!(port("port", Value::from("8080")).0);
+        assert!(!port("port", Value::from("http")).0);
+        assert!(!port("port", Value::from(-80)).0);
+        assert!(!port("port", Value::Null).0);
+    }
+
+    #[test]
+    fn test_hostname() {
+        use super::*;
+
+        assert!(hostname("host", Value::from("example.com")).0);
+        assert!(hostname("host", Value::from("localhost")).0);
+        assert!(hostname("host", Value::from("my-host.example.com")).0);
+        assert!(!hostname("host", Value::from("-example.com")).0);
+        assert!(!hostname("host", Value::from("example-.com")).0);
+        assert!(!hostname("host", Value::from("exa_mple.com")).0);
+        assert!(!hostname("host", Value::from("example..com")).0);
+        assert!(!hostname("host", Value::from(format!("{}.com", "a".repeat(64)))).0); // long label
+        assert!(!hostname("host", Value::from(vec!["abcdefgh"; 30].join("."))).0); // long name
+        assert!(!hostname("host", Value::Null).0);
+        assert!(!hostname("host", Value::from(42)).0);
+    }
+
+    #[test]
+    fn test_ignore_case() {
+        use super::*;
+
+        assert!(contains_ignore_case("query", "Rust", Value::from("learning RUST today")).0);
+        assert!(contains_ignore_case("query", "rust", Value::from("Rustacean")).0);
+        assert!(!contains_ignore_case("query", "rust", Value::from("golang")).0);
+        assert!(!contains_ignore_case("query", "rust", Value::Null).0);
+        assert!(!contains_ignore_case("query", "rust", Value::from(42)).0);
+        assert!(!contains("query", "rust", Value::from("RUST")).0);
+
+        assert!(equals_ignore_case("status", "active", Value::from("ACTIVE")).0);
+        assert!(equals_ignore_case("status", "Active", Value::from("active")).0);
+        assert!(!equals_ignore_case("status", "active", Value::from("inactive")).0);
+        assert!(!equals_ignore_case("status", "active", Value::Null).0);
+        assert!(!equals("status", "active", Value::from("Active")).0);
+    }
+
+    #[test]
+    fn test_field_equals() {
+        use super::*;
+
+        let data = serde_json::json!({ "country": "US", "code": 1, "address": { "zip": null } });
+        let map = data.as_object().unwrap();
+
+        assert!(field_equals("country", "US", map));
+        assert!(field_equals("code", "1", map));
+        assert!(!field_equals("country", "NG", map));
+        assert!(!field_equals("address.zip", "null", map));
+        assert!(!field_equals("missing", "", map));
+    }
+
+    #[test]
+    fn test_barcodes() {
+        use super::*;
+
+        assert!(ean13("barcode", Value::from("4006381333931")).0);
+        assert!(ean13("barcode", Value::from("9780306406157")).0);
+        assert!(!ean13("barcode", Value::from("4006381333932")).0); // wrong check digit
+        assert!(!ean13("barcode", Value::from("400638133393")).0); // wrong length
+        assert!(!ean13("barcode", Value::from("40063813339a1")).0);
+        assert!(!ean13("barcode", Value::Null).0);
+
+        // barcodes sent as JSON numbers are checked like their digits
+        assert!(ean13("barcode", Value::from(4006381333931u64)).0);
+        assert!(!ean13("barcode", Value::from(4006381333932u64)).0);
+        assert!(!ean13("barcode", Value::from(-4006381333931i64)).0);
+        assert!(!ean13("barcode", Value::from(4006381333931.0)).0);
+        assert!(!ean13("barcode", Value::from(true)).0);
+
+        assert!(upc_a("barcode", Value::from("036000291452")).0);
+        assert!(!upc_a("barcode", Value::from("036000291453")).0); // wrong check digit
+        assert!(!upc_a("barcode", Value::from("4006381333931")).0); // wrong length
+        assert!(!upc_a("barcode", Value::Null).0);
+        assert!(upc_a("barcode", Value::from(614141000036u64)).0);
+        assert!(!upc_a("barcode", Value::from(36000291452u64)).0); // the leading zero is lost
+    }
+
+    #[test]
+    fn test_isbn() {
+        use super::*;
+
+        assert!(isbn10("isbn", Value::from("0-8044-2957-X")).0);
+        assert!(isbn10("isbn", Value::from("0 306 40615 2")).0);
+        assert!(!isbn10("isbn", Value::from("0-8044-2957-5")).0); // wrong check digit
+        assert!(!isbn10("isbn", Value::from("0-8044-X957-5")).0); // X is only allowed as check digit
+        assert!(!isbn10("isbn", Value::from("0-8044-2957")).0);
+        assert!(!isbn10("isbn", Value::Null).0);
+        assert!(isbn10("isbn", Value::from(1566199093)).0);
+        assert!(!isbn10("isbn", Value::from(vec!["0-8044-2957-X"])).0);
+
+        assert!(isbn13("isbn", Value::from("978-0-306-40615-7")).0);
+        assert!(isbn13("isbn", Value::from("9780306406157")).0);
+        assert!(!isbn13("isbn", Value::from("978-0-306-40615-8")).0); // wrong check digit
+        assert!(!isbn13("isbn", Value::from("0-8044-2957-X")).0);
+        assert!(!isbn13("isbn", Value::Null).0);
+        assert!(isbn13("isbn", Value::from(9780306406157u64)).0);
+        assert!(!isbn13("isbn", Value::from(false)).0);
+    }
+
+    #[test]
+    fn test_ulid() {
+        use super::*;
+
+        assert!(ulid("id", Value::from("01ARZ3NDEKTSV4RRFFQ69G5FAV")).0);
+        assert!(!ulid("id", Value::from("01ARZ3NDEKTSV4RRFFQ69G5FA")).0); // too short
+        assert!(!ulid("id", Value::from("01ARZ3NDEKTSV4RRFFQ69G5FAVX")).0); // too long
+        assert!(!ulid("id", Value::from("01ARZ3NDEKTSV4RRFFQ69G5FAU")).0); // U isn't allowed
+        assert!(!ulid("id", Value::from("01ARZ3NDEKTSV4RRFFQ69G5FAI")).0);
+        assert!(!ulid("id", Value::from("01arz3ndektsv4rrffq69g5fav")).0); // lowercase
+        assert!(!ulid("id", Value::from("81ARZ3NDEKTSV4RRFFQ69G5FAV")).0); // timestamp overflow
+        assert!(!ulid("id", Value::Null).0);
+        assert!(!ulid("id", Value::from(42)).0);
+    }
+
+    #[test]
+    fn test_uuid_version() {
+        use super::*;
+
+        let v4 = "550e8400-e29b-41d4-a716-446655440000";
+        let v1 = "c232ab00-9414-11ec-b3c8-9f68deced846";
+
+        assert!(uuid_version("id", &4, Value::from(v4)).0);
+        assert!(uuid_version("id", &4, Value::from(v4.to_uppercase())).0);
+        assert!(!uuid_version("id", &4, Value::from(v1)).0);
+        assert!(uuid_version("id", &1, Value::from(v1)).0);
+        assert!(!uuid_version("id", &4, Value::from("550e8400-e29b-41d4-c716-446655440000")).0); // wrong variant
+        assert!(!uuid_version("id", &4, Value::from("550e8400e29b41d4a716446655440000")).0);
+        assert!(!uuid_version("id", &4, Value::from("550e8400-e29b-41d4-a716-44665544000g")).0);
+        assert!(!uuid_version("id", &4, Value::Null).0);
+        assert!(!uuid_version("id", &4, Value::from(4)).0);
+
+        let err = uuid_version("id", &4, Value::from(v1)).1;
+        assert_eq!(err, "'id' field must be a valid version 4 UUID.");
+    }
+
+    #[test]
+    fn test_ascii() {
+        use super::*;
+
+        assert!(ascii("username", Value::from("rust_fan 42!")).0);
+        assert!(ascii("username", Value::from("tab\there")).0);
+        assert!(!ascii("username", Value::from("rust🦀")).0);
+        assert!(!ascii("username", Value::from("café")).0);
+        assert!(!ascii("username", Value::Null).0);
+        assert!(!ascii("username", Value::from(42)).0);
+
+        assert!(ascii_printable("username", Value::from("rust_fan 42!")).0);
+        assert!(!ascii_printable("username", Value::from("tab\there")).0);
+        assert!(!ascii_printable("username", Value::from("bell\u{7}")).0);
+        assert!(!ascii_printable("username", Value::from("rust🦀")).0);
+        assert!(!ascii_printable("username", Value::Null).0);
+        assert!(!ascii_printable("username", Value::from(vec!["abc"])).0);
+    }
+
+    #[test]
+    fn test_luhn() {
+        use super::*;
+
+        assert!(luhn("imei", Value::from("490154203237518")).0);
+        assert!(luhn("imei", Value::from("79927398713")).0);
+        assert!(!luhn("imei", Value::from("490154203237519")).0); // wrong check digit
+        assert!(!luhn("imei", Value::from("79927398710")).0);
+        assert!(!luhn("imei", Value::from("7992 7398 713")).0);
+        assert!(!luhn("imei", Value::from("0")).0);
+        assert!(!luhn("imei", Value::Null).0);
+        assert!(luhn("imei", Value::from(79927398713u64)).0);
+        assert!(!luhn("imei", Value::from(79927398710u64)).0);
+        assert!(!luhn("imei", Value::from(-79927398713i64)).0);
+        assert!(!luhn("imei", Value::from(7992739871.3)).0);
+    }
+
+    #[test]
+    fn test_time() {
+        use super::*;
+
+        assert!(time("opens_at", Value::from("23:59")).0);
+        assert!(time("opens_at", Value::from("00:00")).0);
+        assert!(time("opens_at", Value::from("09:00:05")).0);
+        assert!(!time("opens_at", Value::from("24:00")).0);
+        assert!(!time("opens_at", Value::from("12:60")).0);
+        assert!(!time("opens_at", Value::from("12:30:60")).0);
+        assert!(!time("opens_at", Value::from("9:00")).0);
+        assert!(!time("opens_at", Value::from("09:00:00:00")).0);
+        assert!(!time("opens_at", Value::from("+9:00")).0);
+        assert!(!time("opens_at", Value::Null).0);
+        assert!(!time("opens_at", Value::from(930)).0);
+    }
+
+    #[test]
+    fn test_max_decimal_places() {
+        use super::*;
+
+        assert!(max_decimal_places("price", &2, Value::from("9.99")).0);
+        assert!(max_decimal_places("price", &2, Value::from("9.9")).0);
+        assert!(max_decimal_places("price", &2, Value::from("10")).0);
+        assert!(!max_decimal_places("price", &2, Value::from("9.999")).0);
+        assert!(!max_decimal_places("price", &2, Value::from("9.995")).0);
+
+        assert!(max_decimal_places("price", &2, Value::from(9.99)).0);
+        assert!(max_decimal_places("price", &2, Value::from(10)).0);
+        assert!(!max_decimal_places("price", &2, Value::from(9.995)).0);
+
+        assert!(max_decimal_places("price", &4, Value::from("1.5e-3")).0); // 0.0015
+        assert!(!max_decimal_places("price", &3, Value::from("1.5e-3")).0);
+        assert!(max_decimal_places("price", &0, Value::from("1.25e2")).0);
+
+        assert!(!max_decimal_places("price", &2, Value::from("abc")).0);
+        assert!(!max_decimal_places("price", &2, Value::from("NaN")).0);
+        assert!(!max_decimal_places("price", &2, Value::Null).0);
+    }
+
+    #[test]
+    fn test_json_shape() {
+        use super::*;
+
+        assert!(json_array("payload", Value::from(r#"[1, "two", {"three": 3}]"#)).0);
+        assert!(!json_array("payload", Value::from(r#"{"a": 1}"#)).0);
+        assert!(!json_array("payload", Value::from("42")).0);
+        assert!(!json_array("payload", Value::from("[1, 2")).0);
+        assert!(!json_array("payload", serde_json::json!([1, 2])).0); // not a string
+        assert!(!json_array("payload", Value::Null).0);
+
+        assert!(json_object("payload", Value::from(r#"{"a": [1, 2]}"#)).0);
+        assert!(!json_object("payload", Value::from("[1, 2]")).0);
+        assert!(!json_object("payload", Value::from(r#""text""#)).0);
+        assert!(!json_object("payload", Value::Null).0);
+    }
+
+    #[test]
+    fn test_array_length() {
+        use super::*;
+
+        let tags = |n: usize| Value::from(vec!["tag"; n]);
+
+        assert!(!array_length("tags", tags(0), &1, &5).0);
+        assert!(array_length("tags", tags(1), &1, &5).0);
+        assert!(array_length("tags", tags(5), &1, &5).0);
+        assert!(!array_length("tags", tags(6), &1, &5).0);
+
+        let InnerValidationResult(status, err) = array_length("tags", Value::from("tag"), &1, &5);
+        assert!(!status);
+        assert_eq!(err, "'tags' field must be an array.");
+        assert!(!array_length("tags", Value::Null, &1, &5).0);
+    }
+
+    #[test]
+    fn test_unique_items() {
+        use super::*;
+
+        assert!(unique_items("tags", serde_json::json!(["rust", "serde", "json"])).0);
+        assert!(unique_items("tags", serde_json::json!([])).0);
+        assert!(unique_items("tags", serde_json::json!([1, "1", { "id": 1 }])).0);
+        assert!(!unique_items("tags", serde_json::json!(["rust", "serde", "rust"])).0);
+        assert!(!unique_items("tags", serde_json::json!([{ "id": 1 }, { "id": 1 }])).0);
+
+        let InnerValidationResult(status, err) = unique_items("tags", serde_json::json!([1, 1]));
+        assert!(!status);
+        assert_eq!(err, "'tags' field must not contain duplicate items.");
+        assert!(!unique_items("tags", Value::Null).0);
+    }
+
+    #[test]
+    fn test_array_contains() {
+        use super::*;
+
+        assert!(array_contains("tags", "rust", serde_json::json!(["serde", "rust"])).0);
+        assert!(array_contains("ids", "42", serde_json::json!([1, 42])).0);
+        assert!(!array_contains("tags", "rust", serde_json::json!(["serde", "Rust", "rustacean"])).0);
+        assert!(!array_contains("tags", "rust", serde_json::json!([])).0);
+        assert!(!array_contains("tags", "rust", Value::from("rust")).0);
+    }
+
+    #[test]
+    fn test_postal_code() {
+        use super::*;
+
+        assert!(postal_code("zip", "US", Value::from("94105")).0);
+        assert!(postal_code("zip", "US", Value::from("94105-1234")).0);
+        assert!(!postal_code("zip", "US", Value::from("94105-12")).0);
+        assert!(!postal_code("zip", "US", Value::from("9410A")).0);
+
+        assert!(postal_code("zip", "CA", Value::from("K1A 0B1")).0);
+        assert!(postal_code("zip", "ca", Value::from("k1a0b1")).0);
+        assert!(!postal_code("zip", "CA", Value::from("K1A 0B")).0);
+
+        assert!(postal_code("zip", "GB", Value::from("SW1A 1AA")).0);
+        assert!(postal_code("zip", "GB", Value::from("M1 1AE")).0);
+        assert!(!postal_code("zip", "GB", Value::from("SW1A-1AA")).0);
+
+        // unknown countries use the loose format
+        assert!(postal_code("zip", "KE", Value::from("00100")).0);
+        assert!(!postal_code("zip", "KE", Value::from("0")).0);
+        assert!(!postal_code("zip", "KE", Value::from("00--100")).0);
+        assert!(!postal_code("zip", "US", Value::Null).0);
+        assert!(!postal_code("zip", "US", Value::from(10001)).0);
+    }
+
+    #[test]
+    fn test_vat_number() {
+        use super::*;
+
+        assert!(vat_number("vat", Value::from("DE123456789")).0);
+        assert!(vat_number("vat", Value::from("de 123 456 789")).0);
+        assert!(vat_number("vat", Value::from("ATU12345678")).0);
+        assert!(vat_number("vat", Value::from("NL123456789B01")).0);
+        assert!(vat_number("vat", Value::from("FRXX123456789")).0);
+
+        assert!(!vat_number("vat", Value::from("DE12345678")).0);
+        assert!(!vat_number("vat", Value::from("DE1234567890")).0);
+        assert!(!vat_number("vat", Value::from("AT12345678")).0);
+        assert!(!vat_number("vat", Value::from("US123456789")).0);
+        assert!(!vat_number("vat", Value::from("DE")).0);
+        assert!(!vat_number("vat", Value::Null).0);
+        assert!(!vat_number("vat", Value::from(123456789)).0);
+    }
+
+    #[test]
+    fn test_size_64() {
+        use super::*;
+
+        let big = i32::MAX as i64 + 10;
+
+        assert!(min_size_i64("id", &big, Value::from(big)).0);
+        assert!(min_size_i64("id", &big, Value::from(u64::MAX)).0);
+        assert!(!min_size_i64("id", &big, Value::from(big - 1)).0);
+        assert!(!min_size_i64("id", &big, Value::from(-1)).0);
+        assert!(min_size_i64("id", &i64::MIN, Value::from(i64::MIN)).0);
+        assert!(!min_size_i64("id", &big, Value::from(1.5)).0);
+        assert!(!min_size_i64("id", &big, Value::Null).0);
+
+        let max = u32::MAX as u64 + 10;
+        assert!(max_size_u64("id", &max, Value::from(max)).0);
+        assert!(max_size_u64("id", &max, Value::from(-5)).0);
+        assert!(!max_size_u64("id", &max, Value::from(max + 1)).0);
+        assert!(!max_size_u64("id", &max, Value::from(u64::MAX)).0);
+        assert!(!max_size_u64("id", &max, Value::Null).0);
+
+        // both rules compare the full JSON integer range numerically and parse numeric strings
+        assert!(!min_size_i64("id", &10, Value::from(i64::MIN)).0);
+        assert!(max_size_u64("id", &0, Value::from(i64::MIN)).0);
+        assert!(min_size_i64("id", &10, Value::from("42")).0);
+        assert!(!min_size_i64("id", &10, Value::from("9")).0);
+        assert!(max_size_u64("id", &10, Value::from("-5")).0);
+        assert!(!max_size_u64("id", &10, Value::from(u64::MAX.to_string())).0);
+        assert!(!max_size_u64("id", &10, Value::from("abc")).0);
+        assert!(!max_size_u64("id", &10, Value::from(9.5)).0);
+    }
+
+    #[test]
+    fn test_domain() {
+        use super::*;
+
+        assert!(domain("domain", Value::from("example.com")).0);
+        assert!(domain("domain", Value::from("sub.example.co.uk")).0);
+        assert!(domain("domain", Value::from("example.com.")).0);
+        assert!(!domain("domain", Value::from("localhost")).0);
+        assert!(!domain("domain", Value::from("localhost.")).0);
+        assert!(!domain("domain", Value::from("example.com..")).0);
+        assert!(!domain("domain", Value::from("example.c")).0);
+        assert!(!domain("domain", Value::from("example.123")).0);
+        assert!(!domain("domain", Value::from("-example.com")).0);
+        assert!(!domain("domain", Value::Null).0);
+        assert!(!domain("domain", Value::from(true)).0);
+    }
+
+    #[test]
+    fn test_http_url() {
+        use super::*;
+
+        assert!(http_url("webhook", Value::from("https://x.com")).0);
+        assert!(http_url("webhook", Value::from("http://localhost:8080/hooks?id=1#top")).0);
+        assert!(http_url("webhook", Value::from("HTTPS://example.com/")).0);
+        assert!(!http_url("webhook", Value::from("ftp://x.com")).0);
+        assert!(!http_url("webhook", Value::from("mailto:me@x.com")).0);
+        assert!(!http_url("webhook", Value::from("x.com")).0);
+        assert!(!http_url("webhook", Value::from("https://")).0);
+        assert!(!http_url("webhook", Value::from("https://x.com:0")).0);
+        assert!(!http_url("webhook", Value::from("https://x.com:99999")).0);
+        assert!(!http_url("webhook", Value::from("https://x.com/a b")).0);
+        assert!(!http_url("webhook", Value::Null).0);
+        assert!(!http_url("webhook", Value::from(80)).0);
+    }
+
+    #[test]
+    fn test_contains_all_any() {
+        use super::*;
+
+        let terms = ["privacy", "cookies"];
+
+        assert!(contains_all("policy", &terms, Value::from("our privacy and cookies policy")).0);
+        let InnerValidationResult(status, err) = contains_all("policy", &terms, Value::from("our privacy policy"));
+        assert!(!status);
+        assert_eq!(err, "'policy' field must contain 'cookies'.");
+        let InnerValidationResult(status, err) = contains_all("policy", &terms, Value::from("our policy"));
+        assert!(!status);
+        assert_eq!(err, "'policy' field must contain 'privacy', 'cookies'.");
+        assert!(!contains_all("policy", &terms, Value::Null).0);
+        assert!(!contains_all("policy", &terms, Value::from(42)).0);
+
+        assert!(contains_any("policy", &terms, Value::from("our privacy and cookies policy")).0);
+        assert!(contains_any("policy", &terms, Value::from("our privacy policy")).0);
+        assert!(contains_any("policy", &terms, Value::from("our cookies policy")).0);
+        let InnerValidationResult(status, err) = contains_any("policy", &terms, Value::from("our policy"));
+        assert!(!status);
+        assert_eq!(err, "'policy' field must contain one of 'privacy', 'cookies'.");
+        assert!(!contains_any("policy", &terms, Value::Null).0);
+        assert!(!contains_any("policy", &terms, Value::from(42)).0);
+    }
+
+    #[test]
+    fn test_must_be_true_false() {
+        use super::*;
+
+        assert!(must_be_true("terms", Value::from(true)).0);
+        assert!(!must_be_true("terms", Value::from(false)).0);
+        assert!(!must_be_true("terms", Value::from("true")).0);
+        assert!(!must_be_true("terms", Value::Null).0);
+
+        assert!(must_be_false("opted_out", Value::from(false)).0);
+        assert!(!must_be_false("opted_out", Value::from(true)).0);
+        assert!(!must_be_false("opted_out", Value::from(0)).0);
+        assert!(!must_be_false("opted_out", Value::Null).0);
+    }
+
+    #[test]
+    fn test_bool() {
+        use super::*;
+
+        // Bool only checks the type...
+        assert!(check_bool("allow", Value::from(true)).0);
+        assert!(check_bool("allow", Value::from(false)).0);
+        assert!(!check_bool("allow", Value::from("true")).0);
+        assert!(!check_bool("allow", Value::from(1)).0);
+        assert!(!check_bool("allow", Value::Null).0);
+
+        // ...while MustBeTrue and MustBeFalse check the value
+        assert!(!must_be_true("allow", Value::from(false)).0);
+        assert!(!must_be_false("allow", Value::from(true)).0);
+    }
+
+    #[test]
+    fn test_timezone() {
+        use super::*;
+
+        assert!(codes::TIMEZONES.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(timezone("tz", Value::from("Africa/Lagos")).0);
+        assert!(timezone("tz", Value::from("America/New_York")).0);
+        assert!(timezone("tz", Value::from("America/Argentina/Buenos_Aires")).0);
+        assert!(timezone("tz", Value::from("UTC")).0);
+        assert!(!timezone("tz", Value::from("Foo/Bar")).0);
+        assert!(!timezone("tz", Value::from("africa/lagos")).0);
+        assert!(!timezone("tz", Value::Null).0);
+        assert!(!timezone("tz", Value::from(1)).0);
+    }
+
+    #[test]
+    fn test_cron() {
+        use super::*;
+
+        assert!(cron("schedule", Value::from("0 0 * * *")).0);
+        assert!(cron("schedule", Value::from("*/15 9-17 * * 1,3,5")).0);
+        assert!(cron("schedule", Value::from("0 0-30/10 0 1 1-12/2 7")).0); // with seconds
+        assert!(!cron("schedule", Value::from("60 0 * * *")).0); // minute out of range
+        assert!(!cron("schedule", Value::from("0 0 0 * *")).0); // day of month starts at 1
+        assert!(!cron("schedule", Value::from("0 0 * 13 *")).0);
+        assert!(!cron("schedule", Value::from("0 0 * * 8")).0);
+        assert!(!cron("schedule", Value::from("0 0 * *")).0); // too few fields
+        assert!(!cron("schedule", Value::from("0 0 * * * * *")).0);
+        assert!(!cron("schedule", Value::from("a 0 * * *")).0); // malformed field
+        assert!(!cron("schedule", Value::from("*/0 0 * * *")).0);
+        assert!(!cron("schedule", Value::from("5-1 0 * * *")).0);
+        assert!(!cron("schedule", Value::from("1,,2 0 * * *")).0);
+        assert!(!cron("schedule", Value::Null).0);
+        assert!(!cron("schedule", Value::from(5)).0);
+    }
+
+    #[test]
+    fn test_css_color() {
+        use super::*;
+
+        assert!(css_color("theme", Value::from("#f00")).0);
+        assert!(css_color("theme", Value::from("#FF000080")).0);
+        assert!(css_color("theme", Value::from("rgb(255,0,0)")).0);
+        assert!(css_color("theme", Value::from("rgba(0, 0, 0, 0.5)")).0);
+        assert!(css_color("theme", Value::from("hsl(120,50%,50%)")).0);
+        assert!(css_color("theme", Value::from("HSLA(360, 100%, 0%, 1)")).0);
+
+        assert!(!css_color("theme", Value::from("rgb(300,0,0)")).0); // out of range
+        assert!(!css_color("theme", Value::from("rgba(0,0,0,1.5)")).0);
+        assert!(!css_color("theme", Value::from("rgb(0,0,0,0.5)")).0); // alpha needs rgba
+        assert!(!css_color("theme", Value::from("hsl(120,50,50)")).0); // missing %
+        assert!(!css_color("theme", Value::from("hsl(400,50%,50%)")).0);
+        assert!(!css_color("theme", Value::from("#ff000")).0);
+        assert!(!css_color("theme", Value::from("#ggg")).0);
+        assert!(!css_color("theme", Value::from("red")).0);
+        assert!(!css_color("theme", Value::Null).0);
+        assert!(!css_color("theme", Value::from(0xff0000)).0);
+    }
+
+    #[test]
+    fn test_file_extension() {
+        use super::*;
+
+        let images = ["jpg", "png"];
+
+        assert!(file_extension("upload", &images, Value::from("photo.JPG")).0);
+        assert!(file_extension("upload", &images, Value::from("my.holiday.png")).0);
+        assert!(!file_extension("upload", &images, Value::from("photo.gif")).0);
+        assert!(!file_extension("upload", &images, Value::from("photo")).0); // no extension
+        assert!(!file_extension("upload", &images, Value::from(".png")).0); // dotfile
+        assert!(!file_extension("upload", &images, Value::from("photo.")).0);
+        assert!(!file_extension("upload", &images, Value::Null).0);
+        assert!(!file_extension("upload", &images, Value::from(1)).0);
+
+        assert!(file_extension("upload", &["gz"], Value::from("archive.tar.gz")).0);
+        assert!(!file_extension("upload", &["tar"], Value::from("archive.tar.gz")).0);
+    }
+
+    #[test]
+    fn test_mime_type() {
+        use super::*;
+
+        assert!(mime_type("content_type", Value::from("application/json")).0);
+        assert!(mime_type("content_type", Value::from("text/html; charset=utf-8")).0);
+        assert!(mime_type("content_type", Value::from("image/svg+xml")).0);
+        assert!(mime_type("content_type", Value::from("Application/vnd.api+json")).0);
+        assert!(mime_type("content_type", Value::from("multipart/form-data; boundary=\"abc\"")).0);
+        assert!(!mime_type("content_type", Value::from("notamime")).0);
+        assert!(!mime_type("content_type", Value::from("foo/bar")).0); // unknown top-level type
+        assert!(!mime_type("content_type", Value::from("text/")).0);
+        assert!(!mime_type("content_type", Value::from("text/html; charset")).0);
+        assert!(!mime_type("content_type", Value::from("text/html;")).0);
+        assert!(!mime_type("content_type", Value::Null).0);
+        assert!(!mime_type("content_type", Value::from(false)).0);
+    }
+
+    #[test]
+    fn test_bounds() {
+        use super::*;
+        use std::ops::Bound::*;
+
+        // inclusive, exclusive and unbounded ends
+        assert!(size_bounds("age", Value::from(0), &Included(0), &Unbounded).0);
+        assert!(!size_bounds("age", Value::from(-1), &Included(0), &Unbounded).0);
+        assert!(!size_bounds("age", Value::from(0), &Excluded(0), &Unbounded).0);
+        assert!(size_bounds("age", Value::from(1), &Excluded(0), &Unbounded).0);
+        assert!(size_bounds("age", Value::from(10), &Unbounded, &Included(10)).0);
+        assert!(!size_bounds("age", Value::from(10), &Unbounded, &Excluded(10)).0);
+        assert!(size_bounds("age", Value::from(isize::MIN), &Unbounded, &Unbounded).0);
+        assert!(size_bounds("age", Value::from(5), &Excluded(0), &Excluded(10)).0);
+        assert!(!size_bounds("age", Value::from(11), &Included(0), &Included(10)).0);
+        assert!(!size_bounds("age", Value::Null, &Unbounded, &Unbounded).0);
+
+        assert!(length_bounds("name", Value::from("abc"), &Included(3), &Excluded(5)).0);
+        assert!(!length_bounds("name", Value::from("abcde"), &Included(3), &Excluded(5)).0);
+        assert!(!length_bounds("name", Value::from("ab"), &Included(3), &Unbounded).0);
+        assert!(!length_bounds("name", Value::from(1234), &Included(3), &Unbounded).0);
+
+        assert!(float_bounds("ratio", Value::from(0.5), &Excluded(0.0), &Included(1.0)).0);
+        assert!(!float_bounds("ratio", Value::from(0.0), &Excluded(0.0), &Included(1.0)).0);
+        assert!(float_bounds("ratio", Value::from(1), &Excluded(0.0), &Included(1.0)).0);
+
+        // numeric strings are parsed like the Float* rules, while other values fail instead of panicking
+        assert!(float_bounds("ratio", Value::from("0.5"), &Excluded(0.0), &Included(1.0)).0);
+        assert!(float_positive("ratio", Value::from("0.5")).0);
+        assert!(!float_bounds("ratio", Value::from("NaN"), &Unbounded, &Unbounded).0);
+        assert!(!float_bounds("ratio", Value::from("abc"), &Unbounded, &Unbounded).0);
+        assert!(size_bounds("age", Value::from("10"), &Included(0), &Included(10)).0);
+        assert!(!size_bounds("age", Value::from(1.5), &Unbounded, &Unbounded).0);
+        assert!(!size_bounds("age", Value::from("abc"), &Unbounded, &Unbounded).0);
 
-        assert_eq!(len_status, true);
-        assert_eq!(size_status, false);
-        assert_eq!(req_status, true);
-        assert_eq!(bool_status, false);
-        assert_eq!(pass_status, true);
-        assert_eq!(email_status, false);
-        assert_eq!(rlen_status, true);
-        assert_eq!(slen_status, false);
-        assert_eq!(cont_status, false);
+        assert_eq!(size_bounds("age", Value::from(-1), &Included(0), &Unbounded).1, "'age' field must be at least 0.");
+        assert_eq!(
+            float_bounds("ratio", Value::from(2), &Excluded(0.0), &Included(1.0)).1,
+            "'ratio' field must be greater than 0 and at most 1."
+        );
+        assert_eq!(
+            length_bounds("name", Value::from("abcde"), &Unbounded, &Excluded(5)).1,
+            "'name' field's length must be less than 5."
+        );
     }
 }
@@ -0,0 +1,58 @@
+#![cfg(feature = "actix")]
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use freeval::integrations::actix::Valid;
+use freeval::Validate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Validate)]
+struct SignUp {
+    #[validate(length(min = 3, max = 20))]
+    username: String,
+    #[validate(password(min = 8))]
+    password: String,
+}
+
+async fn sign_up(Valid(data): Valid<SignUp>) -> String {
+    format!("welcome {}", data.username)
+}
+
+async fn send(body: serde_json::Value) -> (StatusCode, String) {
+    let app = test::init_service(App::new().route("/sign-up", web::post().to(sign_up))).await;
+    let request = test::TestRequest::post().uri("/sign-up").set_json(body).to_request();
+
+    let response = test::call_service(&app, request).await;
+    let status = response.status();
+    let body = test::read_body(response).await;
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[actix_web::test]
+async fn test_actix_valid() {
+    let (status, body) = send(serde_json::json!({ "username": "prodbyola", "password": "WhatAPass@003" })).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "welcome prodbyola");
+}
+
+#[actix_web::test]
+async fn test_actix_invalid() {
+    let (status, body) = send(serde_json::json!({ "username": "ab", "password": "WhatAPass@003" })).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+        serde_json::json!({
+            "status": 422,
+            "errors": { "username": [{ "code": "length_out_of_range", "message": "username's length must be between 3 and 20." }] }
+        })
+    );
+}
+
+#[actix_web::test]
+async fn test_actix_malformed() {
+    let (status, _) = send(serde_json::json!({ "username": "prodbyola" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "axum")]
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Request, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use freeval::integrations::axum::Valid;
+use freeval::Validate;
+use serde::{Deserialize, Serialize};
+use tower::ServiceExt;
+
+#[derive(Deserialize, Serialize, Validate)]
+struct SignUp {
+    #[validate(length(min = 3, max = 20))]
+    username: String,
+    #[validate(password(min = 8))]
+    password: String,
+}
+
+async fn sign_up(Valid(data): Valid<SignUp>) -> String {
+    format!("welcome {}", data.username)
+}
+
+async fn send(body: &'static str) -> (StatusCode, String) {
+    let app = Router::new().route("/sign-up", post(sign_up));
+    let request = Request::post("/sign-up")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_axum_valid() {
+    let (status, body) = send(r#"{ "username": "prodbyola", "password": "WhatAPass@003" }"#).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "welcome prodbyola");
+}
+
+#[tokio::test]
+async fn test_axum_invalid() {
+    let (status, body) = send(r#"{ "username": "ab", "password": "WhatAPass@003" }"#).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+        serde_json::json!({
+            "status": 422,
+            "errors": { "username": [{ "code": "length_out_of_range", "message": "username's length must be between 3 and 20." }] }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_axum_malformed() {
+    let (status, _) = send(r#"{ "username": "prodbyola" }"#).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send("not json").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,81 @@
+// the derived rules include `email`, which needs the `regex` feature
+#![cfg(feature = "regex")]
+
+use freeval::Validate;
+use serde::Serialize;
+
+#[derive(Serialize, Validate)]
+struct SignUp {
+    #[validate(length(min = 3, max = 20), required)]
+    username: String,
+    #[validate(email(message = "Please enter a valid email"))]
+    email: String,
+    #[validate(size(min = 18, message = "You're under-aged!"))]
+    age: u8,
+    #[validate(password(min = 8))]
+    password: String,
+    #[validate(must_match(other = "password"))]
+    password_confirmation: String,
+    #[validate(contains(pattern = "rust"), length(max = 40))]
+    bio: String,
+    nickname: Option<String>,
+}
+
+fn valid() -> SignUp {
+    SignUp {
+        username: "prodbyola".to_string(),
+        email: "myemail@gmail.com".to_string(),
+        age: 36,
+        password: "WhatAPass@003".to_string(),
+        password_confirmation: "WhatAPass@003".to_string(),
+        bio: "I love rust".to_string(),
+        nickname: None,
+    }
+}
+
+#[test]
+fn test_derive_valid() {
+    assert!(valid().validate().is_ok());
+}
+
+#[test]
+fn test_derive_invalid() {
+    let data = SignUp {
+        username: "ab".to_string(),
+        email: "myemail@gmailcom".to_string(),
+        age: 12,
+        password_confirmation: "WhatAPass@004".to_string(),
+        bio: "I love go and I will keep writing about it forever".to_string(),
+        ..valid()
+    };
+
+    let errs = data.validate().unwrap_err().messages();
+    let fields: Vec<&String> = errs.keys().collect();
+
+    assert_eq!(fields, vec!["age", "bio", "email", "password_confirmation", "username"]);
+    assert_eq!(errs["age"], vec!["You're under-aged!"]);
+    assert_eq!(errs["email"], vec!["Please enter a valid email"]);
+    assert_eq!(errs["bio"].len(), 2);
+    assert_eq!(errs["username"], vec!["username's length must be between 3 and 20."]);
+}
+
+#[derive(Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+struct Profile {
+    #[validate(length(min = 3))]
+    display_name: String,
+    #[serde(rename = "mail", skip_serializing_if = "Option::is_none")]
+    #[validate(required)]
+    email_address: Option<String>,
+}
+
+#[test]
+fn test_derive_serde_rename() {
+    let data = Profile { display_name: "ab".to_string(), email_address: Some("myemail@gmail.com".to_string()) };
+    let errs = data.validate().unwrap_err().messages();
+    let fields: Vec<&String> = errs.keys().collect();
+    assert_eq!(fields, vec!["displayName"]);
+
+    let data = Profile { display_name: "prodbyola".to_string(), email_address: Some("myemail@gmail.com".to_string()) };
+    assert!(data.validate().is_ok());
+}